@@ -0,0 +1,98 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct Item {
+    id: u32,
+    name: String,
+}
+
+impl RestPath<()> for Item {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("items")) }
+}
+
+// Path type for getting whole array with `get::<_, Vec<Item>>`.
+struct Items;
+
+impl RestPath<Items> for Vec<Item> {
+    fn get_path(_: Items) -> Result<String,Error> { Ok(String::from("items")) }
+}
+
+fn chunked(chunks: &[&str]) -> Vec<u8> {
+    let mut res = String::from("HTTP/1.1 200 OK\r\nConnection: close\r\nTransfer-Encoding: chunked\r\n\r\n");
+    for chunk in chunks {
+        res.push_str(&format!("{:x}\r\n{}\r\n", chunk.len(), chunk));
+    }
+    res.push_str("0\r\n\r\n");
+    res.into_bytes()
+}
+
+#[test]
+fn get_vec() {
+    let url = common::serve(vec![
+        common::response("200 OK", &[], b"[{\"id\":1,\"name\":\"a\"},{\"id\":2,\"name\":\"b\"}]"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let items: Vec<Item> = client.get(Items).unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1].id, 2);
+    assert_eq!(items[1].name, "b");
+}
+
+#[test]
+fn get_array_streaming() {
+    let url = common::serve(vec![chunked(&[
+        " [ {\"id\":1,\"na", "me\":\"a, [b]\"}, {\"id\":2,\"name\":\"\\\"}\"}",
+        ",\n{\"id\":3,\"name\":\"c\"} ]",
+    ])]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let items: Vec<Item> = client.get_array_streaming::<_, Item>(()).unwrap()
+        .map(|item| item.unwrap())
+        .collect();
+
+    assert_eq!(items.iter().map(|item| item.id).collect::<Vec<u32>>(), vec![1, 2, 3]);
+    assert_eq!(items[0].name, "a, [b]");
+    assert_eq!(items[1].name, "\"}");
+}
+
+#[test]
+fn get_array_streaming_empty() {
+    let url = common::serve(vec![common::response("200 OK", &[], b" [ ] ")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    assert!(client.get_array_streaming::<_, Item>(()).unwrap().next().is_none());
+}
+
+#[test]
+fn get_array_streaming_not_array() {
+    let url = common::serve(vec![common::response("200 OK", &[], b"{\"id\":1,\"name\":\"a\"}")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let mut items = client.get_array_streaming::<_, Item>(()).unwrap();
+    match items.next() {
+        Some(Err(Error::ParseError(_))) => (),
+        _ => panic!("expected parse error"),
+    };
+    assert!(items.next().is_none());
+}
+
+#[test]
+fn get_array_streaming_truncated() {
+    let url = common::serve(vec![common::response("200 OK", &[], b"[{\"id\":1,\"name\":\"a\"},{\"id\":2")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let mut items = client.get_array_streaming::<_, Item>(()).unwrap();
+    assert_eq!(items.next().unwrap().unwrap().id, 1);
+    match items.next() {
+        Some(Err(Error::ParseError(_))) => (),
+        _ => panic!("expected parse error"),
+    };
+}
@@ -0,0 +1,134 @@
+extern crate restson;
+extern crate futures;
+extern crate tokio_core;
+
+#[macro_use]
+extern crate serde_derive;
+
+use futures::Future;
+use tokio_core::reactor::Core;
+use std::collections::HashMap;
+use restson::{AsyncRestClient,RestClient,RestPath,Error};
+
+#[derive(Serialize,Deserialize)]
+struct HttpBinAnything {
+    url: String,
+}
+
+impl RestPath<()> for HttpBinAnything {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+impl RestPath<u32> for HttpBinAnything {
+    fn get_path(param: u32) -> Result<String,Error> { Ok(format!("anything/{}", param)) }
+}
+
+#[derive(Deserialize)]
+struct HttpBinHeaders {
+    headers: HashMap<String, String>,
+}
+
+impl RestPath<()> for HttpBinHeaders {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[derive(Serialize,Deserialize)]
+struct HttpBinPost {
+    data: String,
+}
+
+#[derive(Deserialize)]
+struct HttpBinPostResp {
+    json: HttpBinPost,
+}
+
+impl RestPath<()> for HttpBinPost {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("post")) }
+}
+
+#[derive(Deserialize)]
+struct HttpBinStatus {
+}
+
+impl RestPath<u16> for HttpBinStatus {
+    fn get_path(code: u16) -> Result<String,Error> { Ok(format!("status/{}", code)) }
+}
+
+#[test]
+fn get_async() {
+    let mut core = Core::new().unwrap();
+    let client = AsyncRestClient::new("http://httpbin.org", &core.handle()).unwrap();
+
+    let data: HttpBinAnything = core.run(client.get_async(())).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything");
+}
+
+#[test]
+fn get_async_concurrent() {
+    let mut core = Core::new().unwrap();
+    let client = AsyncRestClient::new("http://httpbin.org", &core.handle()).unwrap();
+
+    let a = client.get_async::<_, HttpBinAnything>(1);
+    let b = client.get_async::<_, HttpBinAnything>(2);
+    let (a, b) = core.run(a.join(b)).unwrap();
+
+    assert_eq!(a.url, "http://httpbin.org/anything/1");
+    assert_eq!(b.url, "http://httpbin.org/anything/2");
+}
+
+#[test]
+fn post_capture_async() {
+    let mut core = Core::new().unwrap();
+    let client = AsyncRestClient::new("http://httpbin.org", &core.handle()).unwrap();
+
+    let data = HttpBinPost { data: String::from("test data")};
+    let resp: HttpBinPostResp = core.run(client.post_capture_async((), &data)).unwrap();
+    assert_eq!(resp.json.data, "test data");
+}
+
+#[test]
+fn async_error_status() {
+    let mut core = Core::new().unwrap();
+    let client = AsyncRestClient::new("http://httpbin.org", &core.handle()).unwrap();
+
+    match core.run(client.get_async::<_, HttpBinStatus>(404)) {
+        Err(Error::HttpError(404, _)) => (),
+        _ => panic!("expected 404 error"),
+    };
+}
+
+#[test]
+fn async_builder_settings() {
+    let mut core = Core::new().unwrap();
+    let client = RestClient::builder()
+        .base_url("http://httpbin.org")
+        .user_agent("async-test/1.0")
+        .build_async(&core.handle())
+        .unwrap();
+
+    let data: HttpBinHeaders = core.run(client.get_async(())).unwrap();
+    assert_eq!(data.headers["User-Agent"], "async-test/1.0");
+}
+
+#[test]
+fn async_max_response_size() {
+    let mut core = Core::new().unwrap();
+    let mut client = AsyncRestClient::new("http://httpbin.org", &core.handle()).unwrap();
+    client.set_max_response_size(10);
+
+    match core.run(client.get_async::<_, HttpBinAnything>(())) {
+        Err(Error::ResponseTooLarge) => (),
+        _ => panic!("expected response too large error"),
+    };
+}
+
+#[test]
+fn async_connect_error() {
+    let mut core = Core::new().unwrap();
+    let client = AsyncRestClient::new("http://127.0.0.1:1", &core.handle()).unwrap();
+
+    match core.run(client.get_async::<_, HttpBinAnything>(())) {
+        Err(Error::ConnectError(_)) => (),
+        _ => panic!("expected connect error"),
+    };
+}
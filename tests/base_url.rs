@@ -0,0 +1,45 @@
+extern crate restson;
+
+mod common;
+
+use restson::{RestClient,RestPath,Error};
+
+struct Resource {
+}
+
+impl RestPath<()> for Resource {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("resource")) }
+}
+
+#[test]
+fn set_base_url() {
+    let (first, first_rx) = common::serve_recording(vec![common::response("200 OK", &[], b"first")]);
+    let (second, second_rx) = common::serve_recording(vec![common::response("200 OK", &[], b"second")]);
+    let mut client = RestClient::new(&first).unwrap();
+    client.set_header_raw("X-Api-Key", "secret");
+
+    assert_eq!(client.get_raw::<_, Resource>(()).unwrap(), "first");
+
+    client.set_base_url(&format!("{}/api/v2", second)).unwrap();
+    assert_eq!(client.get_raw::<_, Resource>(()).unwrap(), "second");
+
+    assert!(first_rx.recv().unwrap().starts_with("GET /resource "));
+    let head = second_rx.recv().unwrap();
+    assert!(head.starts_with("GET /api/v2/resource "));
+    assert!(head.to_lowercase().contains("x-api-key: secret"));
+}
+
+#[test]
+fn set_base_url_invalid() {
+    let (url, rx) = common::serve_recording(vec![common::response("200 OK", &[], b"ok")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    match client.set_base_url("1234") {
+        Err(Error::UrlError) => (),
+        _ => panic!("expected url error"),
+    };
+
+    // The previous base URL is still used.
+    assert_eq!(client.get_raw::<_, Resource>(()).unwrap(), "ok");
+    assert!(rx.recv().unwrap().starts_with("GET /resource "));
+}
@@ -0,0 +1,99 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration,Instant};
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinDelay {
+    url: String,
+}
+
+impl RestPath<u32> for HttpBinDelay {
+    fn get_path(delay: u32) -> Result<String,Error> { Ok(format!("delay/{}", delay)) }
+}
+
+#[derive(Deserialize)]
+struct HttpBinAnything {
+}
+
+impl RestPath<&str> for HttpBinAnything {
+    fn get_path(path: &str) -> Result<String,Error> { Ok(path.to_string()) }
+}
+
+#[test]
+fn get_batch() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let start = Instant::now();
+    let results = client.get_batch::<_, HttpBinDelay>(vec![1, 1, 1, 1]);
+    assert!(start.elapsed() < Duration::from_secs(3));
+
+    assert_eq!(results.len(), 4);
+    for res in results {
+        assert_eq!(res.unwrap().url, "http://httpbin.org/delay/1");
+    }
+}
+
+#[test]
+fn get_batch_errors() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let results = client.get_batch::<_, HttpBinAnything>(vec!["status/404", "anything", "status/500"]);
+    match results[0] {
+        Err(Error::HttpError(404, _)) => (),
+        _ => panic!("expected 404 error"),
+    };
+    assert!(results[1].is_ok());
+    match results[2] {
+        Err(Error::HttpError(500, _)) => (),
+        _ => panic!("expected 500 error"),
+    };
+}
+
+#[test]
+fn get_batch_limit() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_max_concurrent_requests(2);
+
+    let start = Instant::now();
+    let results = client.get_batch::<_, HttpBinDelay>(vec![1, 1, 1, 1]);
+    assert!(start.elapsed() >= Duration::from_secs(2));
+    assert!(results.iter().all(|res| res.is_ok()));
+}
+
+#[test]
+fn get_batch_timeout() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_timeout(Duration::from_millis(500));
+
+    let results = client.get_batch::<_, HttpBinDelay>(vec![0, 2]);
+    assert!(results[0].is_ok());
+    match results[1] {
+        Err(Error::TimeoutError) => (),
+        _ => panic!("expected timeout"),
+    };
+}
+
+#[test]
+fn get_batch_checks_responses() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_max_response_headers(1);
+
+    let events = Rc::new(Cell::new(0));
+    let count = events.clone();
+    client.set_observer(move |_| count.set(count.get() + 1));
+
+    let results = client.get_batch::<_, HttpBinAnything>(vec!["anything", "anything"]);
+    for res in results {
+        match res {
+            Err(Error::TooManyHeaders) => (),
+            _ => panic!("expected too many headers error"),
+        };
+    }
+    assert_eq!(events.get(), 2);
+}
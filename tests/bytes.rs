@@ -0,0 +1,191 @@
+extern crate restson;
+
+mod common;
+
+use std::io;
+use std::io::Write;
+use restson::{RestClient,RestPath,Error};
+
+struct HttpBinBytes {
+}
+
+impl RestPath<u32> for HttpBinBytes {
+    fn get_path(n: u32) -> Result<String,Error> { Ok(format!("bytes/{}", n)) }
+}
+
+struct HttpBinBase64 {
+}
+
+impl RestPath<&str> for HttpBinBase64 {
+    fn get_path(data: &str) -> Result<String,Error> { Ok(format!("base64/{}", data)) }
+}
+
+struct HttpBinRobots {
+}
+
+impl RestPath<()> for HttpBinRobots {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("robots.txt")) }
+}
+
+#[test]
+fn get_raw() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let text = client.get_raw::<_, HttpBinRobots>(()).unwrap();
+    assert_eq!(text, "User-agent: *\nDisallow: /deny\n");
+}
+
+#[test]
+fn get_raw_invalid_utf8() {
+    let url = common::serve(vec![common::response("200 OK", &[], &[0xff, 0xfe])]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    match client.get_raw::<_, HttpBinRobots>(()) {
+        Err(Error::ParseError(_)) => (),
+        _ => panic!("expected parse error"),
+    };
+}
+
+#[test]
+fn get_bytes() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = client.get_bytes::<_, HttpBinBytes>(1024).unwrap();
+    assert_eq!(data.len(), 1024);
+}
+
+#[test]
+fn get_bytes_transfer_encoding() {
+    let url = common::serve(vec![
+        common::response("200 OK", &[("Content-Transfer-Encoding", "base64")], b"AAEC/w==\r\n"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let data = client.get_bytes::<_, HttpBinBytes>(4).unwrap();
+    assert_eq!(data, vec![0, 1, 2, 255]);
+}
+
+#[test]
+fn get_base64() {
+    let url = common::serve(vec![
+        common::response("200 OK", &[], b"AAEC\r\n/w=="),
+        common::response("200 OK", &[], b"not base64!"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let data = client.get_base64::<_, HttpBinBase64>("").unwrap();
+    assert_eq!(data, vec![0, 1, 2, 255]);
+
+    match client.get_base64::<_, HttpBinBase64>("") {
+        Err(Error::ParseError(_)) => (),
+        _ => panic!("expected parse error"),
+    };
+}
+
+#[test]
+fn get_bytes_decoded_by_server() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = client.get_bytes::<_, HttpBinBase64>("aHR0cGJpbg==").unwrap();
+    assert_eq!(data, b"httpbin");
+}
+
+#[test]
+fn max_response_size() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_max_response_size(1000);
+
+    let data = client.get_bytes::<_, HttpBinBytes>(1000).unwrap();
+    assert_eq!(data.len(), 1000);
+
+    match client.get_bytes::<_, HttpBinBytes>(1001) {
+        Err(Error::ResponseTooLarge) => (),
+        _ => panic!("expected response too large error"),
+    };
+
+    client.set_max_response_size(0);
+    client.get_bytes::<_, HttpBinBytes>(1001).unwrap();
+}
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn get_to_writer() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let mut out = Vec::new();
+    let written = client.get_to_writer::<_, HttpBinBytes, _>(2048, &mut out).unwrap();
+    assert_eq!(written, 2048);
+    assert_eq!(out.len(), 2048);
+
+    match client.get_to_writer::<_, HttpBinBytes, _>(2048, &mut FailingWriter) {
+        Err(Error::RequestError(Some(e))) => assert_eq!(e.to_string(), "disk full"),
+        _ => panic!("expected request error"),
+    };
+
+    client.set_max_response_size(1000);
+    match client.get_to_writer::<_, HttpBinBytes, _>(2048, &mut Vec::new()) {
+        Err(Error::ResponseTooLarge) => (),
+        _ => panic!("expected response too large error"),
+    };
+}
+
+struct HttpBinAnything {
+}
+
+impl RestPath<()> for HttpBinAnything {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn post_bytes() {
+    let (url, rx) = common::serve_recording(vec![common::response("204 No Content", &[], b"")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.post_bytes::<_, HttpBinAnything>((), vec![0x00, 0xff, 0x10], "application/octet-stream").unwrap();
+
+    let head = rx.recv().unwrap().to_lowercase();
+    assert!(head.contains("content-type: application/octet-stream"));
+    assert!(head.contains("content-length: 3"));
+}
+
+#[test]
+fn post_bytes_capture() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let body = client.post_bytes_capture::<_, HttpBinAnything>((), b"plain text".to_vec(), "text/plain").unwrap();
+    let body = String::from_utf8(body).unwrap();
+    assert!(body.contains("\"data\": \"plain text\""));
+    assert!(body.contains("\"Content-Type\": \"text/plain\""));
+    assert!(body.contains("\"method\": \"POST\""));
+}
+
+#[test]
+fn patch_bytes_capture() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let body = client.patch_bytes_capture::<_, HttpBinAnything>((), b"patch".to_vec(), "application/x-custom").unwrap();
+    let body = String::from_utf8(body).unwrap();
+    assert!(body.contains("\"data\": \"patch\""));
+    assert!(body.contains("\"method\": \"PATCH\""));
+}
+
+#[test]
+fn post_bytes_invalid_content_type() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    match client.post_bytes::<_, HttpBinAnything>((), vec![1, 2, 3], "not a media type") {
+        Err(Error::ParseError(_)) => (),
+        _ => panic!("expected parse error"),
+    };
+}
@@ -0,0 +1,39 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use std::time::Duration;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize,Clone)]
+struct Config {
+    version: u32,
+}
+
+impl RestPath<()> for Config {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("config")) }
+}
+
+#[test]
+fn get_cached() {
+    let url = common::serve(vec![
+        common::response("200 OK", &[], br#"{"version":1}"#),
+        common::response("200 OK", &[], br#"{"version":2}"#),
+        common::response("200 OK", &[], br#"{"version":3}"#),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let ttl = Duration::from_secs(60);
+    assert_eq!(client.get_cached::<_, Config>((), ttl).unwrap().version, 1);
+    assert_eq!(client.get_cached::<_, Config>((), ttl).unwrap().version, 1);
+
+    // Expired value is fetched again.
+    assert_eq!(client.get_cached::<_, Config>((), Duration::from_secs(0)).unwrap().version, 2);
+    assert_eq!(client.get_cached::<_, Config>((), ttl).unwrap().version, 2);
+
+    client.clear_cache();
+    assert_eq!(client.get_cached::<_, Config>((), ttl).unwrap().version, 3);
+}
@@ -0,0 +1,121 @@
+extern crate restson;
+extern crate futures;
+extern crate tokio_core;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::thread;
+use std::time::{Duration,Instant};
+use futures::Future;
+use futures::sync::oneshot;
+use tokio_core::reactor::Core;
+use restson::{RestClient,AsyncRestClient,RestPath,CancelToken,Error};
+
+#[derive(Deserialize)]
+struct HttpBinDelay {
+}
+
+impl RestPath<u32> for HttpBinDelay {
+    fn get_path(delay: u32) -> Result<String,Error> { Ok(format!("delay/{}", delay)) }
+}
+
+fn cancel_after(token: &CancelToken, delay: Duration) {
+    let token = token.clone();
+    thread::spawn(move || {
+        thread::sleep(delay);
+        token.cancel();
+    });
+}
+
+#[test]
+fn get_cancellable() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let token = CancelToken::new();
+    cancel_after(&token, Duration::from_millis(200));
+
+    let start = Instant::now();
+    match client.get_cancellable::<_, HttpBinDelay>(5, &token) {
+        Err(Error::Cancelled) => (),
+        _ => panic!("expected cancelled error"),
+    };
+    assert!(start.elapsed() < Duration::from_secs(3));
+    assert!(token.is_cancelled());
+
+    // Client is usable after cancellation.
+    client.get::<_, HttpBinDelay>(0).unwrap();
+}
+
+#[test]
+fn get_cancellable_completed() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let token = CancelToken::new();
+    client.get_cancellable::<_, HttpBinDelay>(0, &token).unwrap();
+
+    token.cancel();
+    match client.get_cancellable::<_, HttpBinDelay>(0, &token) {
+        Err(Error::Cancelled) => (),
+        _ => panic!("expected cancelled error"),
+    };
+}
+
+#[test]
+fn get_cancellable_async() {
+    let mut core = Core::new().unwrap();
+    let client = AsyncRestClient::new("http://httpbin.org", &core.handle()).unwrap();
+
+    let (req, token) = client.get_cancellable::<_, HttpBinDelay>(5);
+    cancel_after(&token, Duration::from_millis(200));
+
+    let start = Instant::now();
+    match core.run(req) {
+        Err(Error::Cancelled) => (),
+        _ => panic!("expected cancelled error"),
+    };
+    assert!(start.elapsed() < Duration::from_secs(3));
+}
+
+#[test]
+fn get_cancellable_reused_token() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    // Completed requests leave nothing registered to the token, so it can be
+    // reused for any number of requests and still cancels the pending one.
+    let token = CancelToken::new();
+    for _ in 0..20 {
+        client.get_cancellable::<_, HttpBinDelay>(0, &token).unwrap();
+    }
+
+    cancel_after(&token, Duration::from_millis(200));
+    match client.get_cancellable::<_, HttpBinDelay>(5, &token) {
+        Err(Error::Cancelled) => (),
+        _ => panic!("expected cancelled error"),
+    };
+}
+
+#[test]
+fn get_cancellable_async_queued() {
+    let mut core = Core::new().unwrap();
+    let handle = core.handle();
+    let mut client = AsyncRestClient::new("http://httpbin.org", &handle).unwrap();
+    client.set_max_concurrent_requests(1);
+    client.set_timeout(Duration::from_secs(4));
+
+    // The first request holds the only permit while the second waits for it
+    // and is cancelled. The third, waiting in its own task, must get the
+    // permit when the first completes.
+    let first = client.get_async::<_, HttpBinDelay>(1);
+    let (second, token) = client.get_cancellable::<_, HttpBinDelay>(0);
+    let (tx, rx) = oneshot::channel();
+    handle.spawn(second.then(|res| {
+        assert!(matches!(res, Err(Error::Cancelled)));
+        Ok(())
+    }));
+    handle.spawn(client.get_async::<_, HttpBinDelay>(0).then(|res| tx.send(res.map(|_| ())).map_err(|_| ())));
+    cancel_after(&token, Duration::from_millis(200));
+
+    core.run(first).unwrap();
+    core.run(rx).unwrap().unwrap();
+}
@@ -0,0 +1,59 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::thread;
+use std::time::Duration;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinAnything {
+    headers: Headers,
+}
+
+#[derive(Deserialize)]
+struct Headers {
+    #[serde(rename = "X-Api-Key")]
+    api_key: String,
+}
+
+impl RestPath<()> for HttpBinAnything {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn try_clone() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_header_raw("X-Api-Key", "1234");
+    client.get::<_, HttpBinAnything>(()).unwrap();
+
+    let mut clone = client.try_clone().unwrap();
+    let data: HttpBinAnything = clone.get(()).unwrap();
+    assert_eq!(data.headers.api_key, "1234");
+
+    // The clone has its own connection pool.
+    assert_eq!(client.connections_established(), 1);
+    assert_eq!(clone.connections_established(), 1);
+}
+
+#[test]
+fn builder_per_thread() {
+    let builder = RestClient::builder()
+        .base_url("http://httpbin.org")
+        .timeout(Duration::from_secs(10))
+        .default_header("X-Api-Key", "1234");
+
+    let threads: Vec<_> = (0..4).map(|_| {
+        let builder = builder.clone();
+        thread::spawn(move || {
+            let mut client = builder.build().unwrap();
+            let data: HttpBinAnything = client.get(()).unwrap();
+            data.headers.api_key
+        })
+    }).collect();
+
+    for thread in threads {
+        assert_eq!(thread.join().unwrap(), "1234");
+    }
+}
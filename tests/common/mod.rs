@@ -0,0 +1,141 @@
+#![allow(dead_code)]
+
+// Minimal HTTP server for responses that httpbin cannot produce.
+
+use std::io;
+use std::io::{BufRead,BufReader,Read,Write};
+use std::net::{TcpListener,TcpStream};
+use std::sync::mpsc::{channel,Receiver};
+use std::thread;
+
+/// Serves the given raw HTTP responses, one per connection, in order.
+///
+/// Returns the base URL of the server.
+pub fn serve(responses: Vec<Vec<u8>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    thread::spawn(move || {
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            read_request(&mut stream);
+            let _ = stream.write_all(&response);
+        }
+    });
+
+    url
+}
+
+/// Like `serve`, but also passes each received request to the returned
+/// channel: its head (request line and headers), an empty line and its
+/// body, decoded if it was sent chunked.
+pub fn serve_recording(responses: Vec<Vec<u8>>) -> (String, Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = channel();
+
+    thread::spawn(move || {
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let (head, body) = read_request_with_body(&mut stream);
+            let _ = tx.send(format!("{}\r\n{}", head, String::from_utf8_lossy(&body)));
+            let _ = stream.write_all(&response);
+        }
+    });
+
+    (url, rx)
+}
+
+/// Starts HTTP proxy that tunnels `CONNECT` requests to their target and
+/// passes the head of each `CONNECT` request to the returned channel.
+///
+/// Returns the URL of the proxy.
+pub fn proxy() -> (String, Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = channel();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut client = stream.unwrap();
+            let head = read_request(&mut client);
+            let target = head.split(' ').nth(1).unwrap().to_string();
+            let _ = tx.send(head);
+
+            let mut server = TcpStream::connect(target.as_str()).unwrap();
+            client.write_all(b"HTTP/1.1 200 Connection established\r\n\r\n").unwrap();
+
+            let mut client_read = client.try_clone().unwrap();
+            let mut server_write = server.try_clone().unwrap();
+            thread::spawn(move || { let _ = io::copy(&mut client_read, &mut server_write); });
+            thread::spawn(move || { let _ = io::copy(&mut server, &mut client); });
+        }
+    });
+
+    (url, rx)
+}
+
+/// Builds raw HTTP response from status line, headers and body.
+pub fn response(status: &str, headers: &[(&str, &str)], body: &[u8]) -> Vec<u8> {
+    let mut res = format!("HTTP/1.1 {}\r\nConnection: close\r\nContent-Length: {}\r\n", status, body.len());
+    for &(name, value) in headers {
+        res.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    res.push_str("\r\n");
+
+    let mut res = res.into_bytes();
+    res.extend_from_slice(body);
+    res
+}
+
+fn read_request(stream: &mut TcpStream) -> String {
+    read_request_with_body(stream).0
+}
+
+fn read_request_with_body(stream: &mut TcpStream) -> (String, Vec<u8>) {
+    let mut reader = BufReader::new(stream);
+    let mut head = String::new();
+    let mut content_length = 0;
+    let mut chunked = false;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+            break;
+        }
+        let lower = line.to_lowercase();
+        if lower.starts_with("content-length:") {
+            content_length = line[15..].trim().parse().unwrap();
+        }
+        if lower.starts_with("transfer-encoding:") && lower.contains("chunked") {
+            chunked = true;
+        }
+        head.push_str(&line);
+    }
+
+    if !chunked {
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        return (head, body);
+    }
+
+    let mut body = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let size = usize::from_str_radix(line.trim().split(';').next().unwrap(), 16).unwrap();
+        if size == 0 {
+            // Last chunk is followed by optional trailers and an empty line.
+            while line != "\r\n" {
+                line.clear();
+                if reader.read_line(&mut line).unwrap() == 0 {
+                    break;
+                }
+            }
+            return (head, body);
+        }
+        let mut chunk = vec![0; size + 2];
+        reader.read_exact(&mut chunk).unwrap();
+        body.extend_from_slice(&chunk[..size]);
+    }
+}
@@ -0,0 +1,118 @@
+extern crate restson;
+extern crate flate2;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use std::io::Write;
+use flate2::Compression;
+use flate2::write::DeflateEncoder;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinGzip {
+    gzipped: bool,
+}
+
+impl RestPath<()> for HttpBinGzip {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("gzip")) }
+}
+
+#[derive(Deserialize)]
+struct HttpBinDeflate {
+    deflated: bool,
+}
+
+impl RestPath<()> for HttpBinDeflate {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("deflate")) }
+}
+
+#[test]
+fn gzip_response() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_accept_compression(true);
+
+    let data: HttpBinGzip = client.get(()).unwrap();
+    assert!(data.gzipped);
+}
+
+#[test]
+fn deflate_response() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_accept_compression(true);
+
+    let data: HttpBinDeflate = client.get(()).unwrap();
+    assert!(data.deflated);
+}
+
+#[test]
+fn truncated_gzip_response() {
+    // Gzip header and start of deflate stream without the rest of the body.
+    let body = [0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xab, 0x56, 0xca];
+    let url = common::serve(vec![
+        common::response("200 OK", &[("Content-Encoding", "gzip")], &body),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_accept_compression(true);
+
+    match client.get::<_, HttpBinGzip>(()) {
+        Err(Error::DecompressionError(_)) => (),
+        _ => panic!("expected decompression error"),
+    };
+}
+
+#[test]
+fn accept_encoding_header() {
+    let (url, requests) = common::serve_recording(vec![
+        common::response("200 OK", &[], br#"{"gzipped":false}"#),
+        common::response("200 OK", &[], br#"{"gzipped":false}"#),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.get::<_, HttpBinGzip>(()).unwrap();
+    assert!(!requests.recv().unwrap().to_lowercase().contains("accept-encoding"));
+
+    // Server may ignore the header and respond uncompressed.
+    client.set_accept_compression(true);
+    let data: HttpBinGzip = client.get(()).unwrap();
+    assert!(!data.gzipped);
+    assert!(requests.recv().unwrap().to_lowercase().contains("accept-encoding: gzip, deflate"));
+}
+
+#[test]
+fn raw_deflate_response() {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(br#"{"deflated":true}"#).unwrap();
+    let body = encoder.finish().unwrap();
+
+    let url = common::serve(vec![
+        common::response("200 OK", &[("Content-Encoding", "deflate")], &body),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_accept_compression(true);
+
+    let data: HttpBinDeflate = client.get(()).unwrap();
+    assert!(data.deflated);
+}
+
+#[test]
+fn decompressed_size_limit() {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(format!(r#"{{"deflated":true,"padding":"{}"}}"#, " ".repeat(10000)).as_bytes()).unwrap();
+    let body = encoder.finish().unwrap();
+    assert!(body.len() < 1000);
+
+    let url = common::serve(vec![
+        common::response("200 OK", &[("Content-Encoding", "deflate")], &body),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_accept_compression(true);
+    client.set_max_response_size(1000);
+
+    match client.get::<_, HttpBinDeflate>(()) {
+        Err(Error::ResponseTooLarge) => (),
+        _ => panic!("expected response too large error"),
+    };
+}
@@ -0,0 +1,55 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::time::SystemTime;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinAnything {
+    method: String,
+}
+
+impl RestPath<&str> for HttpBinAnything {
+    fn get_path(etag: &str) -> Result<String,Error> { Ok(format!("etag/{}", etag)) }
+}
+
+impl RestPath<()> for HttpBinAnything {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("cache")) }
+}
+
+#[test]
+fn if_none_match() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = client.get_if_none_match::<_, HttpBinAnything>("abc", "\"abc\"").unwrap();
+    assert!(data.is_none());
+
+    let data = client.get_if_none_match::<_, HttpBinAnything>("abc", "abc").unwrap();
+    assert!(data.is_none());
+
+    let data = client.get_if_none_match::<_, HttpBinAnything>("abc", "\"other\"").unwrap();
+    assert_eq!(data.unwrap().method, "GET");
+}
+
+#[test]
+fn if_none_match_success_predicate() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_success_predicate(|status| status.is_success() || status.as_u16() == 304);
+
+    let data = client.get_if_none_match::<_, HttpBinAnything>("abc", "\"abc\"").unwrap();
+    assert!(data.is_none());
+}
+
+#[test]
+fn if_modified_since() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = client.get_if_modified_since::<_, HttpBinAnything>((), SystemTime::now()).unwrap();
+    assert!(data.is_none());
+
+    // Unconditional request to the same resource returns the body.
+    let data: HttpBinAnything = client.get(()).unwrap();
+    assert_eq!(data.method, "GET");
+}
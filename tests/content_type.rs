@@ -0,0 +1,68 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use restson::{RestClient,RestPath,Error,MissingContentTypeBehavior};
+
+#[derive(Deserialize)]
+struct Data {
+    id: u32,
+}
+
+impl RestPath<()> for Data {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("data")) }
+}
+
+struct Text;
+
+impl RestPath<Text> for String {
+    fn get_path(_: Text) -> Result<String,Error> { Ok(String::from("text")) }
+}
+
+#[test]
+fn missing_content_type_assume_json() {
+    let url = common::serve(vec![common::response("200 OK", &[], b"{\"id\":1}")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let data: Data = client.get(()).unwrap();
+    assert_eq!(data.id, 1);
+}
+
+#[test]
+fn missing_content_type_error() {
+    let url = common::serve(vec![
+        common::response("200 OK", &[], b"{\"id\":1}"),
+        common::response("200 OK", &[("Content-Type", "application/json")], b"{\"id\":2}"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_missing_content_type_behavior(MissingContentTypeBehavior::Error);
+
+    match client.get::<_, Data>(()) {
+        Err(Error::MissingContentType) => (),
+        _ => panic!("expected missing content type error"),
+    };
+
+    let data: Data = client.get(()).unwrap();
+    assert_eq!(data.id, 2);
+}
+
+#[test]
+fn missing_content_type_assume_text() {
+    let url = common::serve(vec![
+        common::response("200 OK", &[], b"plain text"),
+        common::response("200 OK", &[], b"{\"id\":1}"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_missing_content_type_behavior(MissingContentTypeBehavior::AssumeText);
+
+    let text: String = client.get(Text).unwrap();
+    assert_eq!(text, "plain text");
+
+    match client.get::<_, Data>(()) {
+        Err(Error::ParseError(_)) => (),
+        _ => panic!("expected parse error"),
+    };
+}
@@ -0,0 +1,73 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use std::collections::HashMap;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinCookies {
+    cookies: HashMap<String, String>,
+}
+
+impl RestPath<()> for HttpBinCookies {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("cookies")) }
+}
+
+impl RestPath<&str> for HttpBinCookies {
+    fn get_path(action: &str) -> Result<String,Error> { Ok(format!("cookies/{}", action)) }
+}
+
+#[test]
+fn cookies_disabled() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_follow_redirects(1);
+
+    let data: HttpBinCookies = client.get_with("set", &[("session", "1234")]).unwrap();
+    assert!(data.cookies.is_empty());
+}
+
+#[test]
+fn cookies() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    client.set_follow_redirects(1);
+    client.enable_cookies();
+
+    let data: HttpBinCookies = client.get_with("set", &[("session", "1234"), ("lang", "fi")]).unwrap();
+    assert_eq!(data.cookies.get("session").map(|s| s.as_str()), Some("1234"));
+    assert_eq!(data.cookies.get("lang").map(|s| s.as_str()), Some("fi"));
+
+    let data: HttpBinCookies = client.get_with("delete", &[("lang", "")]).unwrap();
+    assert_eq!(data.cookies.len(), 1);
+
+    client.clear_cookies();
+    let data: HttpBinCookies = client.get(()).unwrap();
+    assert!(data.cookies.is_empty());
+}
+
+#[test]
+fn cookie_attributes() {
+    let (url, requests) = common::serve_recording(vec![
+        common::response("200 OK", &[
+            ("Set-Cookie", "plain=1"),
+            ("Set-Cookie", "secure=2; Secure"),
+            ("Set-Cookie", "scoped=3; Path=/other"),
+            ("Set-Cookie", "expired=4; Expires=Thu, 01 Jan 1970 00:00:00 GMT"),
+            ("Set-Cookie", "foreign=5; Domain=example.com"),
+            ("Content-Type", "application/json"),
+        ], br#"{"cookies":{}}"#),
+        common::response("200 OK", &[("Content-Type", "application/json")], br#"{"cookies":{}}"#),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.enable_cookies();
+
+    let _: HttpBinCookies = client.get(()).unwrap();
+    let _: HttpBinCookies = client.get(()).unwrap();
+
+    assert!(!requests.recv().unwrap().contains("Cookie:"));
+    let head = requests.recv().unwrap();
+    assert!(head.contains("Cookie: plain=1\r\n"), "{}", head);
+}
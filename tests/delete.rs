@@ -1,5 +1,8 @@
 extern crate restson;
 
+#[macro_use]
+extern crate serde_derive;
+
 use restson::{RestClient,RestPath,Error};
 
 struct HttpBinDelete {
@@ -9,9 +12,40 @@ impl RestPath<()> for HttpBinDelete {
     fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("delete")) }
 }
 
+#[derive(Serialize,Deserialize)]
+struct HttpBinDeleteBody {
+    ids: Vec<u32>,
+}
+
+impl RestPath<()> for HttpBinDeleteBody {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("delete")) }
+}
+
+#[derive(Deserialize)]
+struct HttpBinDeleteResp {
+    url: String,
+    json: Option<HttpBinDeleteBody>,
+}
+
 #[test]
 fn basic_delete() {
     let mut client = RestClient::new("http://httpbin.org").unwrap();
 
     client.delete::<(), HttpBinDelete>(()).unwrap();
 }
+
+#[test]
+fn delete_with_body() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.delete_with_body((), &HttpBinDeleteBody { ids: vec![1, 2] }).unwrap();
+}
+
+#[test]
+fn delete_capture() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let resp: HttpBinDeleteResp = client.delete_capture::<_, HttpBinDelete, _>(()).unwrap();
+    assert_eq!(resp.url, "http://httpbin.org/delete");
+    assert!(resp.json.is_none());
+}
@@ -1,14 +1,37 @@
 extern crate restson;
+extern crate hyper;
 
 #[macro_use]
 extern crate serde_derive;
 
-use restson::{RestClient,Error,RestPath};
+mod common;
+
+use restson::{RestClient,Error,RestPath,ApiError,InvalidBody};
+use hyper::StatusCode;
 
 #[derive(Serialize,Deserialize)]
 struct InvalidResource {
 }
 
+#[derive(Deserialize,Debug)]
+struct ErrorBody {
+    message: String,
+}
+
+impl RestPath<()> for ErrorBody {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("status")) }
+}
+
+#[derive(Deserialize,Debug)]
+struct MissingField {
+    #[allow(dead_code)]
+    missing: String,
+}
+
+impl RestPath<()> for MissingField {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
 impl RestPath<()> for InvalidResource {
     fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("not_found")) }
 }
@@ -60,4 +83,181 @@ fn path_error() {
     else {
         panic!("expected url error");
     }
-}
\ No newline at end of file
+}
+#[test]
+fn parse_error_source() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let err = client.get::<(), MissingField>(()).unwrap_err();
+    match err {
+        Error::ParseError(Some(_)) => (),
+        _ => panic!("expected parse error with cause"),
+    };
+    let source = std::error::Error::source(&err).expect("expected source");
+    assert!(source.to_string().contains("missing field"));
+}
+
+#[test]
+fn skip_error_body() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_skip_error_body(true);
+
+    match client.get::<(), InvalidResource>(()) {
+        Err(Error::HttpError(404, body)) => assert!(body.is_empty()),
+        _ => panic!("expected 404 error without body"),
+    };
+}
+
+#[test]
+fn typed_error_fallback() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    // httpbin returns HTML error page that does not match the error type
+    match client.get_typed::<(), InvalidResource, ErrorBody>(()) {
+        Err(ApiError::Client(Error::HttpError(404, body))) => assert!(!body.is_empty()),
+        _ => panic!("expected raw 404 error"),
+    };
+}
+
+#[test]
+fn post_typed_error() {
+    let url = common::serve(vec![
+        common::response("400 Bad Request", &[], br#"{"message":"invalid"}"#),
+        common::response("400 Bad Request", &[], b"bad request"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    match client.post_capture_typed::<_, _, ErrorBody, ErrorBody>((), &InvalidResource {}) {
+        Err(ApiError::Http(400, data)) => assert_eq!(data.message, "invalid"),
+        _ => panic!("expected typed 400 error"),
+    };
+
+    match client.post_capture_typed::<_, _, ErrorBody, ErrorBody>((), &InvalidResource {}) {
+        Err(ApiError::Client(Error::HttpError(400, ref body))) if body == "bad request" => (),
+        _ => panic!("expected raw 400 error"),
+    };
+}
+
+#[test]
+fn any_status() {
+    let url = common::serve(vec![
+        common::response("200 OK", &[], br#"{"message":"ok"}"#),
+        common::response("422 Unprocessable Entity", &[], br#"{"message":"invalid"}"#),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let data = client.get_any_status::<_, ErrorBody>(()).unwrap();
+    assert_eq!(data.message, "ok");
+
+    match client.get_any_status::<_, ErrorBody>(()) {
+        Err(ApiError::Http(422, data)) => assert_eq!(data.message, "invalid"),
+        _ => panic!("expected typed 422 error"),
+    };
+}
+
+#[test]
+fn success_predicate() {
+    let url = common::serve(vec![
+        common::response("409 Conflict", &[], br#"{"message":"exists"}"#),
+        common::response("200 OK", &[], br#"{"message":"ok"}"#),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_success_predicate(|status| status == StatusCode::Conflict);
+
+    let data = client.get::<_, ErrorBody>(()).unwrap();
+    assert_eq!(data.message, "exists");
+
+    match client.get::<_, ErrorBody>(()) {
+        Err(Error::HttpError(200, _)) => (),
+        _ => panic!("expected http error"),
+    };
+}
+
+#[test]
+fn expect_status() {
+    let url = common::serve(vec![
+        common::response("202 Accepted", &[], br#"{"message":"accepted"}"#),
+        common::response("200 OK", &[], br#"{"message":"ok"}"#),
+        common::response("404 Not Found", &[], br#"{"message":"missing"}"#),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let data = client.get_expect_status::<_, ErrorBody>((), StatusCode::Accepted).unwrap();
+    assert_eq!(data.message, "accepted");
+
+    match client.get_expect_status::<_, ErrorBody>((), StatusCode::Accepted) {
+        Err(Error::UnexpectedStatus { expected: 202, actual: 200, ref body }) if body == r#"{"message":"ok"}"# => (),
+        _ => panic!("expected unexpected status error"),
+    };
+
+    let data = client.get_expect_status::<_, ErrorBody>((), StatusCode::NotFound).unwrap();
+    assert_eq!(data.message, "missing");
+}
+
+#[test]
+fn problem_details() {
+    let body = br#"{"type":"https://example.com/out-of-credit","title":"You do not have enough credit.","status":403,"balance":30}"#;
+    let url = common::serve(vec![
+        common::response("403 Forbidden", &[("Content-Type", "application/problem+json")], body),
+        common::response("403 Forbidden", &[("Content-Type", "application/problem+json")], b"<html></html>"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    match client.get::<bool, InvalidResource>(true) {
+        Err(Error::HttpProblem(403, problem)) => {
+            assert_eq!(problem.problem_type, "https://example.com/out-of-credit");
+            assert_eq!(problem.title.unwrap(), "You do not have enough credit.");
+            assert_eq!(problem.status, Some(403));
+            assert_eq!(problem.extensions["balance"], 30);
+        },
+        _ => panic!("expected problem details error"),
+    };
+
+    // body not matching problem details falls back to raw body
+    match client.get::<bool, InvalidResource>(true) {
+        Err(Error::HttpError(403, body)) => assert_eq!(body, "<html></html>"),
+        _ => panic!("expected raw http error"),
+    };
+}
+
+#[test]
+fn display_messages() {
+    assert_eq!(Error::UrlError.to_string(), "invalid URL");
+    assert_eq!(Error::TimeoutError.to_string(), "request timed out");
+    assert_eq!(Error::ParseError(None).to_string(), "failed to parse data");
+    assert_eq!(Error::HttpError(404, String::new()).to_string(), "server returned status 404");
+    assert_eq!(Error::HttpError(500, "oops".to_string()).to_string(), "server returned status 500: oops");
+    assert_eq!(Error::UnexpectedStatus { expected: 201, actual: 200, body: String::new() }.to_string(),
+        "expected status 201, server returned 200");
+
+    let message = Error::HttpError(500, "é".repeat(150)).to_string();
+    assert!(message.starts_with("server returned status 500: éé"));
+    assert!(message.ends_with("..."));
+    assert!(message.len() < 250);
+}
+
+#[test]
+fn boxed_error() {
+    fn get() -> Result<(), Box<dyn std::error::Error>> {
+        let mut client = RestClient::new("http://httpbin.org")?;
+        client.get::<(), InvalidResource>(())?;
+        Ok(())
+    }
+
+    let err = get().unwrap_err();
+    assert!(err.to_string().starts_with("server returned status 404"));
+}
+
+#[test]
+fn parse_error_body() {
+    let url = common::serve(vec![common::response("200 OK", &[("Content-Type", "application/json")], br#"{"renamed":"x"}"#)]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let err = client.get::<(), MissingField>(()).unwrap_err();
+    assert_eq!(err.response_body(), Some(r#"{"renamed":"x"}"#));
+
+    let source = std::error::Error::source(&err).unwrap();
+    let invalid = source.downcast_ref::<InvalidBody>().unwrap();
+    assert!(invalid.cause.to_string().contains("missing field"));
+}
@@ -0,0 +1,42 @@
+extern crate restson;
+extern crate hyper;
+
+use hyper::Method;
+use restson::{RestClient,RestPath,Error};
+
+mod common;
+
+struct Calendar {
+}
+
+impl RestPath<u32> for Calendar {
+    fn get_path(id: u32) -> Result<String,Error> { Ok(format!("calendars/{}", id)) }
+}
+
+#[test]
+fn execute_extension_method() {
+    let (url, requests) = common::serve_recording(vec![
+        common::response("207 Multi-Status", &[("Content-Type", "application/xml")], b"<multistatus/>"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let query = vec![("a", "1")];
+    let method = Method::Extension(String::from("REPORT"));
+    let body = client.execute::<_, Calendar>(method, 1, Some(&query), Some(String::from("{}"))).unwrap();
+    assert_eq!(body, "<multistatus/>");
+
+    let head = requests.recv().unwrap();
+    assert!(head.starts_with("REPORT /calendars/1?a=1 "));
+    assert!(head.contains("Content-Length: 2\r\n"));
+}
+
+#[test]
+fn execute_error_status() {
+    let url = common::serve(vec![common::response("423 Locked", &[], b"")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    match client.execute::<_, Calendar>(Method::Extension(String::from("LOCK")), 1, None, None) {
+        Err(Error::HttpError(423, _)) => (),
+        _ => panic!("expected 423 error"),
+    };
+}
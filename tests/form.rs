@@ -0,0 +1,68 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use restson::{RestClient,RestPath,Error,Format};
+
+mod common;
+
+#[derive(Serialize,Deserialize)]
+struct Token {
+    access_token: String,
+    expires_in: u32,
+}
+
+impl RestPath<()> for Token {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("token")) }
+}
+
+#[derive(Serialize)]
+struct TokenRequest {
+    grant_type: String,
+}
+
+impl RestPath<()> for TokenRequest {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("token")) }
+}
+
+fn token_response() -> Vec<u8> {
+    common::response("200 OK", &[("Content-Type", "application/x-www-form-urlencoded")],
+        b"access_token=abcd%2F1&expires_in=3600")
+}
+
+#[test]
+fn get_form() {
+    let url = common::serve(vec![token_response()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let token: Token = client.get_form(()).unwrap();
+    assert_eq!(token.access_token, "abcd/1");
+    assert_eq!(token.expires_in, 3600);
+}
+
+#[test]
+fn post_capture_form() {
+    let (url, requests) = common::serve_recording(vec![token_response(), token_response()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_send_format(Format::FormUrlEncoded);
+    let data = TokenRequest { grant_type: String::from("client_credentials") };
+    let token: Token = client.post_capture_form((), &data).unwrap();
+    assert_eq!(token.access_token, "abcd/1");
+    assert!(requests.recv().unwrap().contains("Content-Type: application/x-www-form-urlencoded\r\n"));
+
+    // The receive format is restored after the request.
+    let res: Result<Token, Error> = client.post_capture((), &data);
+    assert!(res.is_err());
+}
+
+#[test]
+fn receive_format() {
+    let url = common::serve(vec![token_response()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_receive_format(Format::FormUrlEncoded);
+    let token: Token = client.get(()).unwrap();
+    assert_eq!(token.expires_in, 3600);
+}
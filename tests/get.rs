@@ -3,7 +3,8 @@ extern crate restson;
 #[macro_use]
 extern crate serde_derive;
 
-use restson::{RestClient,RestPath,Error};
+use std::collections::HashMap;
+use restson::{RestClient,RestPath,RestPathSegments,Error,Either,OwnedQuery};
 
 #[derive(Deserialize)]
 struct HttpBinAnything {
@@ -11,6 +12,15 @@ struct HttpBinAnything {
     args: HttpBinAnythingArgs,
 }
 
+#[derive(Deserialize)]
+struct HttpBinAbsolute {
+    url: String,
+}
+
+impl RestPath<()> for HttpBinAbsolute {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("/anything")) }
+}
+
 #[derive(Deserialize)]
 struct HttpBinAnythingArgs {
     #[serde(default)]
@@ -27,13 +37,19 @@ impl RestPath<u32> for HttpBinAnything {
     fn get_path(param: u32) -> Result<String,Error> { Ok(format!("anything/{}", param)) }
 }
 
-impl<'a> RestPath<(u32, &'a str)> for HttpBinAnything {
+impl RestPath<(u32, &str)> for HttpBinAnything {
     fn get_path(param: (u32, &str)) -> Result<String,Error> { 
         let (a,b) = param;
         Ok(format!("anything/{}/{}", a, b))
     }
 }
 
+impl RestPathSegments<&str> for HttpBinAnything {
+    fn get_segments(param: &str) -> Result<Vec<String>,Error> {
+        Ok(vec![String::from("anything"), String::from(param)])
+    }
+}
+
 #[test]
 fn basic_get_http() {
     let mut client = RestClient::new("http://httpbin.org").unwrap();
@@ -50,6 +66,33 @@ fn basic_get_https() {
     assert_eq!(data.url, "https://httpbin.org/anything");
 }
 
+#[test]
+fn get_base_url_path() {
+    let mut client = RestClient::new("http://httpbin.org/anything/api/v1").unwrap();
+    let data: HttpBinAnything = client.get(1234).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything/api/v1/anything/1234");
+
+    let mut client = RestClient::new("http://httpbin.org/anything/api/v1/").unwrap();
+    let data: HttpBinAnything = client.get(1234).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything/api/v1/anything/1234");
+}
+
+#[test]
+fn get_absolute_path() {
+    let mut client = RestClient::new("http://httpbin.org/api/v1").unwrap();
+    let data: HttpBinAbsolute = client.get(()).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything");
+}
+
+#[test]
+fn request_url() {
+    let client = RestClient::new("http://httpbin.org/api").unwrap();
+
+    let url = client.request_url::<_, HttpBinAnything>(1234, Some(&[("a", "1 2")])).unwrap();
+    assert_eq!(url, "http://httpbin.org/api/anything/1234?a=1+2");
+    assert_eq!(client.request_url::<_, HttpBinAnything>((), None).unwrap(), "http://httpbin.org/api/anything");
+}
+
 #[test]
 fn get_path_param() {
     let mut client = RestClient::new("http://httpbin.org").unwrap();
@@ -66,6 +109,134 @@ fn get_multi_path_param() {
     assert_eq!(data.url, "http://httpbin.org/anything/1234/abcd");
 }
 
+#[test]
+fn get_concurrency_limit() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_max_concurrent_requests(1);
+
+    // permit is released after each request
+    let _: HttpBinAnything = client.get(()).unwrap();
+    let _: HttpBinAnything = client.get(()).unwrap();
+}
+
+#[test]
+fn get_response_validator() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_response_validator(|headers, body| {
+        if headers.get_raw("Content-Type").is_some() && body.starts_with(b"{") {
+            return Ok(());
+        }
+        Err(Error::ParseError(None))
+    });
+    let _: HttpBinAnything = client.get(()).unwrap();
+
+    client.set_response_validator(|_, _| Err(Error::ParseError(None)));
+    match client.get::<_, HttpBinAnything>(()) {
+        Err(Error::ParseError(_)) => (),
+        _ => panic!("expected validator error"),
+    };
+}
+
+#[test]
+fn get_path_segments() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data: HttpBinAnything = client.get("john doe/1").unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything/john%20doe%2F1");
+}
+
+#[test]
+fn get_path_encoding() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data: HttpBinAnything = client.get((1, "john doe?")).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything/1/john%20doe%3F");
+
+    let name = RestClient::encode_path_segment("john/doe");
+    assert_eq!(name, "john%2Fdoe");
+    let data: HttpBinAnything = client.get((1, name.as_str())).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything/1/john%2Fdoe");
+}
+
+#[derive(Deserialize)]
+struct HttpBinAnythingMethod {
+    method: String,
+}
+
+#[derive(Deserialize)]
+struct HttpBinMissing {
+    #[allow(dead_code)]
+    missing: String,
+}
+
+impl RestPath<()> for HttpBinMissing {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn get_either() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    match client.get_either::<_, HttpBinAnything, HttpBinAnythingMethod>(()).unwrap() {
+        Either::Left(data) => assert_eq!(data.url, "http://httpbin.org/anything"),
+        Either::Right(_) => panic!("expected first type"),
+    };
+
+    match client.get_either::<_, HttpBinMissing, HttpBinAnythingMethod>(()).unwrap() {
+        Either::Right(data) => assert_eq!(data.method, "GET"),
+        Either::Left(_) => panic!("expected second type"),
+    };
+}
+
+#[derive(Deserialize)]
+struct HttpBinAnythingBorrowed<'a> {
+    url: &'a str,
+}
+
+#[test]
+fn get_borrowed() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let body = client.get_borrowed::<_, HttpBinAnything>(()).unwrap();
+    let data: HttpBinAnythingBorrowed = body.deserialize().unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything");
+}
+
+impl<'a> RestPath<()> for HttpBinAnythingBorrowed<'a> {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn get_with_buffer() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let mut buffer = String::from("previous");
+    {
+        let data: HttpBinAnythingBorrowed = client.get_with_buffer((), &mut buffer).unwrap();
+        assert_eq!(data.url, "http://httpbin.org/anything");
+    }
+    assert!(buffer.starts_with('{'));
+}
+
+#[test]
+fn get_full() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let (data, body) = client.get_full::<_, HttpBinAnything>(()).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything");
+    assert!(body.contains(r#""method": "GET""#));
+}
+
+#[test]
+fn get_typed() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = client.get_typed::<_, HttpBinAnything, String>(()).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything");
+}
+
 #[test]
 fn get_query_params() {
     let mut client = RestClient::new("http://httpbin.org").unwrap();
@@ -77,3 +248,127 @@ fn get_query_params() {
     assert_eq!(data.args.a, "2");
     assert_eq!(data.args.b, "abcd");
 }
+
+#[test]
+fn get_with_response() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let (data, info) = client.get_with_response::<_, HttpBinAnything>(()).unwrap();
+
+    assert_eq!(data.url, "http://httpbin.org/anything");
+    assert_eq!(info.status.as_u16(), 200);
+    assert_eq!(info.headers.get_raw("Content-Type").unwrap().one(), Some(&b"application/json"[..]));
+}
+
+#[test]
+fn get_raw_query() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data: HttpBinAnything = client.get_with_raw_query((), "b=ab%2Bcd&a=%7e2").unwrap();
+
+    assert_eq!(data.url, "http://httpbin.org/anything?b=ab%2Bcd&a=%7e2");
+    assert_eq!(data.args.a, "~2");
+    assert_eq!(data.args.b, "ab+cd");
+}
+
+#[derive(Serialize)]
+struct SearchParams {
+    a: u32,
+    b: Option<String>,
+    c: Option<String>,
+}
+
+#[test]
+fn get_struct_query_params() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let params = SearchParams { a: 2, b: Some(String::from("ab cd")), c: None };
+    let data: HttpBinAnything = client.get_with_params((), &params).unwrap();
+
+    assert_eq!(data.url, "http://httpbin.org/anything?a=2&b=ab+cd");
+    assert_eq!(data.args.a, "2");
+    assert_eq!(data.args.b, "ab cd");
+}
+
+#[test]
+fn get_owned_query_params() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let mut params = HashMap::new();
+    params.insert(String::from("b"), String::from("abcd"));
+    params.insert(String::from("a"), 2.to_string());
+    let params = OwnedQuery::from(params);
+    let data: HttpBinAnything = client.get_with((), &params.as_query()).unwrap();
+
+    assert_eq!(data.url, "http://httpbin.org/anything?a=2&b=abcd");
+    assert_eq!(data.args.a, "2");
+    assert_eq!(data.args.b, "abcd");
+}
+
+#[test]
+fn connection_reuse() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let _: HttpBinAnything = client.get(()).unwrap();
+    let _: HttpBinAnything = client.get(()).unwrap();
+
+    assert_eq!(client.connections_established(), 1);
+    assert_eq!(client.requests_reusing_connection(), 1);
+}
+
+// Base64 encoded response body returned as-is by httpbin
+struct Base64(&'static str);
+
+impl RestPath<Base64> for u64 {
+    fn get_path(param: Base64) -> Result<String,Error> { Ok(format!("base64/{}", param.0)) }
+}
+
+impl RestPath<Base64> for String {
+    fn get_path(param: Base64) -> Result<String,Error> { Ok(format!("base64/{}", param.0)) }
+}
+
+impl RestPath<Base64> for bool {
+    fn get_path(param: Base64) -> Result<String,Error> { Ok(format!("base64/{}", param.0)) }
+}
+
+#[test]
+fn get_primitives() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    // 42
+    let data: u64 = client.get(Base64("NDI=")).unwrap();
+    assert_eq!(data, 42);
+
+    // "hello"
+    let data: String = client.get(Base64("ImhlbGxvIg==")).unwrap();
+    assert_eq!(data, "hello");
+
+    // true
+    let data: bool = client.get(Base64("dHJ1ZQ==")).unwrap();
+    assert!(data);
+}
+
+#[derive(Serialize,Deserialize)]
+struct Search {
+    term: String,
+}
+
+#[derive(Deserialize)]
+struct HttpBinAnythingJson {
+    method: String,
+    json: Search,
+}
+
+impl RestPath<()> for HttpBinAnythingJson {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn get_with_body() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let search = Search { term: String::from("restson") };
+    let data: HttpBinAnythingJson = client.get_with_body((), &search).unwrap();
+    assert_eq!(data.method, "GET");
+    assert_eq!(data.json.term, "restson");
+}
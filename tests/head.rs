@@ -0,0 +1,39 @@
+extern crate restson;
+extern crate hyper;
+
+use hyper::Method;
+use restson::{RestClient,RestPath,Error};
+
+struct HttpBinGet {
+}
+
+impl RestPath<()> for HttpBinGet {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("get")) }
+}
+
+impl RestPath<u16> for HttpBinGet {
+    fn get_path(code: u16) -> Result<String,Error> { Ok(format!("status/{}", code)) }
+}
+
+#[test]
+fn head() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let info = client.head::<_, HttpBinGet>(()).unwrap();
+    assert_eq!(info.status.as_u16(), 200);
+    assert!(info.headers.get_raw("Content-Type").is_some());
+
+    match client.head::<_, HttpBinGet>(404) {
+        Err(Error::HttpError(404, _)) => (),
+        _ => panic!("expected 404 error"),
+    };
+}
+
+#[test]
+fn options() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let methods = client.options::<_, HttpBinGet>(()).unwrap();
+    assert!(methods.contains(&Method::Get));
+    assert!(methods.contains(&Method::Patch));
+}
@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinAnything {
+    url: String,
+}
+
+struct DeviceId(u32);
+
+impl std::fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "dev-{}", self.0)
+    }
+}
+
+impl_rest_path!(HttpBinAnything, DeviceId => "anything/devices/{}");
+impl_rest_path!(HttpBinAnything, (u32, &str) => "anything/{}/users/{}");
+impl_rest_path!(HttpBinAnything, (u32, u32, String) => "anything/{}/{}/{}");
+
+#[test]
+fn single_parameter() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data: HttpBinAnything = client.get(DeviceId(7)).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything/devices/dev-7");
+}
+
+#[test]
+fn multiple_parameters() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data: HttpBinAnything = client.get((1, "john/doe?")).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything/1/users/john%2Fdoe%3F");
+
+    let data: HttpBinAnything = client.get((1, 2, String::from("a b"))).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/anything/1/2/a%20b");
+}
+
+#[test]
+fn error_type() {
+    let path: Result<String, Error> = <HttpBinAnything as RestPath<DeviceId>>::get_path(DeviceId(1));
+    assert_eq!(path.unwrap(), "anything/devices/dev-1");
+}
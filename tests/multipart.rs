@@ -0,0 +1,74 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use restson::{RestClient,RestPath,Error,MultipartPart};
+
+#[derive(Deserialize)]
+struct HttpBinAnything {
+    data: String,
+    headers: Headers,
+}
+
+#[derive(Deserialize)]
+struct Headers {
+    #[serde(rename = "Content-Type")]
+    content_type: String,
+}
+
+struct Upload {
+}
+
+impl RestPath<()> for Upload {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn post_multipart() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let parts = vec![
+        MultipartPart::text("title", "test upload"),
+        MultipartPart::file("file", "notes.txt", "text/plain", b"file contents".to_vec()).unwrap(),
+    ];
+    let data: HttpBinAnything = client.post_multipart_capture::<_, Upload, _>((), parts).unwrap();
+
+    let prefix = "multipart/form-data; boundary=";
+    assert!(data.headers.content_type.starts_with(prefix));
+    let boundary = &data.headers.content_type[prefix.len()..];
+
+    let expected = format!(
+        "--{b}\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\ntest upload\r\n\
+         --{b}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"notes.txt\"\r\n\
+         Content-Type: text/plain\r\n\r\nfile contents\r\n--{b}--\r\n", b = boundary);
+    assert_eq!(data.data, expected);
+}
+
+#[test]
+fn post_multipart_escaped_names() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let parts = vec![MultipartPart::file("up\"load", "a\r\nb.txt", "text/plain", b"x".to_vec()).unwrap()];
+    let data: HttpBinAnything = client.post_multipart_capture::<_, Upload, _>((), parts).unwrap();
+    assert!(data.data.contains("name=\"up%22load\"; filename=\"a%0D%0Ab.txt\""));
+}
+
+#[test]
+fn multipart_invalid_content_type() {
+    match MultipartPart::file("file", "a.txt", "text/plain\r\nX-Injected: 1", b"x".to_vec()) {
+        Err(Error::ParseError(Some(_))) => (),
+        _ => panic!("expected parse error"),
+    };
+}
+
+#[test]
+fn post_multipart_boundary_unique() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let first: HttpBinAnything = client.post_multipart_capture::<_, Upload, _>((), vec![MultipartPart::text("a", "1")]).unwrap();
+    let second: HttpBinAnything = client.post_multipart_capture::<_, Upload, _>((), vec![MultipartPart::text("a", "1")]).unwrap();
+    assert!(first.headers.content_type != second.headers.content_type);
+
+    client.post_multipart::<_, Upload>((), Vec::new()).unwrap();
+}
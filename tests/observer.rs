@@ -0,0 +1,101 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinAnything {
+}
+
+impl RestPath<()> for HttpBinAnything {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+impl RestPath<u16> for HttpBinAnything {
+    fn get_path(status: u16) -> Result<String,Error> { Ok(format!("status/{}", status)) }
+}
+
+#[derive(Deserialize)]
+struct HttpBinHeaders {
+    headers: CorrelationHeader,
+}
+
+#[derive(Deserialize)]
+struct CorrelationHeader {
+    #[serde(rename = "X-Correlation-Id")]
+    id: String,
+}
+
+impl RestPath<()> for HttpBinHeaders {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+struct RequestId(u32);
+
+type Events = Rc<RefCell<Vec<(String, Option<u16>, Option<u32>)>>>;
+
+fn observed_client(url: &str) -> (RestClient, Events) {
+    let mut client = RestClient::new(url).unwrap();
+    let events: Events = Rc::new(RefCell::new(Vec::new()));
+
+    let log = events.clone();
+    client.set_observer(move |event| {
+        let id = event.context.and_then(|c| c.downcast_ref::<RequestId>()).map(|id| id.0);
+        log.borrow_mut().push((event.url.to_string(), event.status, id));
+    });
+
+    (client, events)
+}
+
+#[test]
+fn observer_context() {
+    let (mut client, events) = observed_client("http://httpbin.org");
+
+    client.set_request_context(RequestId(42));
+    client.get::<_, HttpBinAnything>(()).unwrap();
+    client.get::<_, HttpBinAnything>(()).unwrap();
+
+    assert_eq!(*events.borrow(), vec![
+        (String::from("http://httpbin.org/anything"), Some(200), Some(42)),
+        (String::from("http://httpbin.org/anything"), Some(200), None),
+    ]);
+}
+
+#[test]
+fn observer_errors() {
+    let (mut client, events) = observed_client("http://httpbin.org");
+
+    client.set_request_context(RequestId(1));
+    assert!(client.get::<_, HttpBinAnything>(404).is_err());
+    assert_eq!(events.borrow()[0], (String::from("http://httpbin.org/status/404"), Some(404), Some(1)));
+
+    // Nothing listens on this port.
+    let (mut client, events) = observed_client("http://127.0.0.1:9");
+
+    assert!(client.get::<_, HttpBinAnything>(()).is_err());
+    assert_eq!(events.borrow()[0], (String::from("http://127.0.0.1:9/anything"), None, None));
+}
+
+#[test]
+fn request_and_response_hooks() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+    let statuses = Rc::new(RefCell::new(Vec::new()));
+
+    client.set_request_hook(|req| req.headers_mut().set_raw("X-Correlation-Id", "abc123"));
+    let log = statuses.clone();
+    client.set_response_hook(move |status, _| log.borrow_mut().push(status.as_u16()));
+
+    let data: HttpBinHeaders = client.get(()).unwrap();
+    assert_eq!(data.headers.id, "abc123");
+
+    assert!(client.get::<_, HttpBinAnything>(404).is_err());
+    assert_eq!(*statuses.borrow(), vec![200, 404]);
+
+    client.reset();
+    client.get::<_, HttpBinAnything>(()).unwrap();
+    assert_eq!(statuses.borrow().len(), 2);
+}
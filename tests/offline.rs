@@ -0,0 +1,50 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use restson::{RestClient,RestPath,Error,CannedResponse};
+
+#[derive(Deserialize)]
+struct HttpBinAnything {
+    method: String,
+}
+
+impl RestPath<()> for HttpBinAnything {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn offline_response() {
+    // Nothing listens on this address, so any network access would fail.
+    let mut client = RestClient::new("http://127.0.0.1:9").unwrap();
+
+    client.set_offline_mode(Some(CannedResponse::new(200, "{\"method\":\"CANNED\"}")));
+
+    let data: HttpBinAnything = client.get(()).unwrap();
+    assert_eq!(data.method, "CANNED");
+    assert_eq!(client.connections_established(), 0);
+}
+
+#[test]
+fn offline_error() {
+    let mut client = RestClient::new("http://127.0.0.1:9").unwrap();
+
+    client.set_offline_mode(Some(CannedResponse::new(503, "unavailable")));
+
+    match client.get::<_, HttpBinAnything>(()) {
+        Err(Error::HttpError(503, body)) => assert_eq!(body, "unavailable"),
+        _ => panic!("expected canned error"),
+    };
+}
+
+#[test]
+fn offline_disabled() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_offline_mode(Some(CannedResponse::new(200, "{\"method\":\"CANNED\"}")));
+    client.set_offline_mode(None);
+
+    let data: HttpBinAnything = client.get(()).unwrap();
+    assert_eq!(data.method, "GET");
+}
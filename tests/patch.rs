@@ -0,0 +1,61 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Serialize,Deserialize)]
+struct HttpBinPatch {
+    data: String,
+}
+
+#[derive(Deserialize)]
+struct HttpBinPatchResp {
+    json: HttpBinPatch,
+    url: String,
+}
+
+impl RestPath<()> for HttpBinPatch {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("patch")) }
+}
+
+#[test]
+fn basic_patch() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = HttpBinPatch { data: String::from("test data")};
+    client.patch((), &data).unwrap();
+}
+
+#[test]
+fn patch_query_params() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let params = vec![("a","2"), ("b","abcd")];
+    let data = HttpBinPatch { data: String::from("test data")};
+    client.patch_with((), &data, &params).unwrap();
+}
+
+#[test]
+fn patch_capture() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = HttpBinPatch { data: String::from("test data")};
+    let resp: HttpBinPatchResp = client.patch_capture((), &data).unwrap();
+
+    assert_eq!(resp.json.data, "test data");
+    assert_eq!(resp.url, "http://httpbin.org/patch");
+}
+
+#[test]
+fn patch_capture_query_params() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let params = vec![("a","2"), ("b","abcd")];
+    let data = HttpBinPatch { data: String::from("test data")};
+    let resp: HttpBinPatchResp = client.patch_capture_with((), &data, &params).unwrap();
+
+    assert_eq!(resp.json.data, "test data");
+    assert_eq!(resp.url, "http://httpbin.org/patch?a=2&b=abcd");
+}
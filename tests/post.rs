@@ -1,9 +1,15 @@
 extern crate restson;
+#[macro_use]
+extern crate serde_json;
 
 #[macro_use]
 extern crate serde_derive;
 
-use restson::{RestClient,RestPath,Error};
+use std::cell::RefCell;
+use std::rc::Rc;
+use restson::{RestClient,RestPath,Error,Format};
+
+mod common;
 
 #[derive(Serialize,Deserialize)]
 struct HttpBinPost {
@@ -37,6 +43,43 @@ fn post_query_params() {
     client.post_with((), &data, &params).unwrap();
 }
 
+#[derive(Deserialize)]
+struct HttpBinFormResp {
+    form: HttpBinPost,
+    headers: HttpBinFormHeaders,
+}
+
+#[derive(Deserialize)]
+struct HttpBinFormHeaders {
+    #[serde(rename = "Content-Type")]
+    content_type: String,
+}
+
+#[test]
+fn post_form_urlencoded() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_send_format(Format::FormUrlEncoded);
+    let data = HttpBinPost { data: String::from("test data & more")};
+    let resp: HttpBinFormResp = client.post_capture((), &data).unwrap();
+
+    assert_eq!(resp.form.data, "test data & more");
+    assert_eq!(resp.headers.content_type, "application/x-www-form-urlencoded");
+}
+
+#[test]
+fn post_with_serializer() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = HttpBinPost { data: String::from("test data")};
+    client.post_with_serializer((), &data, |data| Ok(format!("{{ \"data\": \"{}\" }}", data.data))).unwrap();
+
+    match client.post_with_serializer((), &data, |_| Err(Error::ParseError(None))) {
+        Err(Error::ParseError(_)) => (),
+        _ => panic!("expected serializer error"),
+    };
+}
+
 #[test]
 fn post_capture() {
     let mut client = RestClient::new("http://httpbin.org").unwrap();
@@ -48,6 +91,18 @@ fn post_capture() {
     assert_eq!(resp.url, "http://httpbin.org/post");
 }
 
+#[test]
+fn post_capture_with_response() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let data = HttpBinPost { data: String::from("test data")};
+    let (resp, info) = client.post_capture_with_response::<_, _, HttpBinPostResp>((), &data).unwrap();
+
+    assert_eq!(resp.json.data, "test data");
+    assert_eq!(info.status.as_u16(), 200);
+    assert!(info.headers.get_raw("Content-Length").is_some());
+}
+
 #[test]
 fn post_capture_query_params() {
     let mut client = RestClient::new("http://httpbin.org").unwrap();
@@ -58,4 +113,117 @@ fn post_capture_query_params() {
 
     assert_eq!(resp.json.data, "test data");
     assert_eq!(resp.url, "http://httpbin.org/post?a=2&b=abcd");
-}
\ No newline at end of file
+}
+
+#[test]
+fn post_ndjson() {
+    let (url, requests) = common::serve_recording(vec![common::response("200 OK", &[], b"")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let items = (0..10).map(|i| HttpBinPost { data: format!("line {}", i) });
+    client.post_ndjson((), items).unwrap();
+
+    let request = requests.recv().unwrap();
+    let (head, body) = request.split_at(request.find("\r\n\r\n").unwrap() + 4);
+    assert!(head.to_lowercase().contains("transfer-encoding: chunked\r\n"));
+    let expected: String = (0..10).map(|i| format!("{{\"data\":\"line {}\"}}\n", i)).collect();
+    assert_eq!(body, expected);
+}
+
+#[test]
+fn post_accounting() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let usage = Rc::new(RefCell::new(Vec::new()));
+    let log = usage.clone();
+    client.set_accounting_callback(move |tag, sent, received| {
+        log.borrow_mut().push((tag.map(String::from), sent, received));
+    });
+    client.set_tenant_tag(Some("acme"));
+
+    let data = HttpBinPost { data: String::from("test data")};
+    client.post((), &data).unwrap();
+
+    let usage = usage.borrow();
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage[0].0, Some(String::from("acme")));
+    assert_eq!(usage[0].1, r#"{"data":"test data"}"#.len() as u64);
+    assert!(usage[0].2 > 0);
+}
+
+#[test]
+fn probe_gzip_support() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let requests = Rc::new(RefCell::new(0));
+    let count = requests.clone();
+    client.set_accounting_callback(move |_, _, _| *count.borrow_mut() += 1);
+
+    assert!(client.probe_gzip_support::<(), HttpBinPost>(()).unwrap());
+
+    // second probe is served from cache
+    assert!(client.probe_gzip_support::<(), HttpBinPost>(()).unwrap());
+    assert_eq!(*requests.borrow(), 1);
+}
+
+#[test]
+fn post_value() {
+    let (url, requests) = common::serve_recording(vec![common::response("204 No Content", &[], b"")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_send_format(Format::FormUrlEncoded);
+    client.post_value("devices", &json!({ "id": 1 })).unwrap();
+
+    let head = requests.recv().unwrap();
+    assert!(head.starts_with("POST /devices "));
+    assert!(head.contains("Content-Type: application/json\r\n"));
+    assert!(head.contains("Content-Length: 8\r\n"));
+}
+
+#[test]
+fn post_string() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.post_string("post", String::from(r#"{"data":"test data"}"#)).unwrap();
+}
+
+#[test]
+fn post_capture_no_content() {
+    let no_content = || common::response("204 No Content", &[], b"");
+    let url = common::serve(vec![no_content(), no_content(), no_content()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let data = HttpBinPost { data: String::from("test data")};
+    let _: () = client.post_capture((), &data).unwrap();
+
+    let resp: Option<HttpBinPost> = client.post_capture((), &data).unwrap();
+    assert!(resp.is_none());
+
+    let err = client.post_capture::<_, _, HttpBinPost>((), &data).err().unwrap();
+    assert!(err.to_string().contains("empty response body"));
+}
+
+#[test]
+fn post_created() {
+    let (url, requests) = common::serve_recording(vec![
+        common::response("201 Created", &[("Location", "/post/42")], b""),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let data = HttpBinPost { data: String::from("test data")};
+    let location = client.post_created((), &data).unwrap();
+    assert_eq!(location, "/post/42");
+    assert!(requests.recv().unwrap().starts_with("POST /post "));
+}
+
+#[test]
+fn post_created_missing_location() {
+    let url = common::serve(vec![common::response("201 Created", &[], b"")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let data = HttpBinPost { data: String::from("test data")};
+    match client.post_created((), &data) {
+        Err(Error::MissingHeader(ref name)) if name == "Location" => (),
+        _ => panic!("expected missing header error"),
+    };
+}
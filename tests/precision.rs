@@ -0,0 +1,35 @@
+#![cfg(feature = "arbitrary_precision")]
+
+extern crate restson;
+extern crate serde_json;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::str::FromStr;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Serialize)]
+struct HttpBinPrecise {
+    value: serde_json::Number,
+}
+
+#[derive(Deserialize)]
+struct HttpBinPreciseResp {
+    data: String,
+}
+
+impl RestPath<()> for HttpBinPrecise {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("post")) }
+}
+
+#[test]
+fn post_arbitrary_precision() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let value = "12345678901234567890123.000000000000000000001";
+    let data = HttpBinPrecise { value: serde_json::Number::from_str(value).unwrap() };
+    let resp: HttpBinPreciseResp = client.post_capture((), &data).unwrap();
+
+    assert_eq!(resp.data, format!("{{\"value\":{}}}", value));
+}
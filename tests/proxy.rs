@@ -0,0 +1,128 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use std::env;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinAnything {
+    method: String,
+}
+
+impl RestPath<()> for HttpBinAnything {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn proxy() {
+    let (proxy, requests) = common::proxy();
+    let mut client = RestClient::builder()
+        .base_url("http://httpbin.org")
+        .proxy(&proxy)
+        .build()
+        .unwrap();
+
+    let data: HttpBinAnything = client.get(()).unwrap();
+    assert_eq!(data.method, "GET");
+
+    let head = requests.recv().unwrap();
+    assert!(head.starts_with("CONNECT httpbin.org:80 HTTP/1.1\r\n"));
+    assert!(!head.contains("Proxy-Authorization"));
+}
+
+#[test]
+fn proxy_https() {
+    let (proxy, requests) = common::proxy();
+    let mut client = RestClient::builder()
+        .base_url("https://httpbin.org")
+        .proxy(&proxy)
+        .build()
+        .unwrap();
+
+    client.get::<_, HttpBinAnything>(()).unwrap();
+    assert!(requests.recv().unwrap().starts_with("CONNECT httpbin.org:443 HTTP/1.1\r\n"));
+}
+
+#[test]
+fn proxy_credentials() {
+    let (proxy, requests) = common::proxy();
+    let mut client = RestClient::builder()
+        .base_url("http://httpbin.org")
+        .proxy(&proxy.replace("http://", "http://user:p%40ss@"))
+        .build()
+        .unwrap();
+
+    client.get::<_, HttpBinAnything>(()).unwrap();
+
+    // base64 of "user:p@ss"
+    assert!(requests.recv().unwrap().contains("Proxy-Authorization: Basic dXNlcjpwQHNz\r\n"));
+}
+
+#[test]
+fn proxy_refused() {
+    let url = common::serve(vec![common::response("407 Proxy Authentication Required", &[], b"")]);
+    let mut client = RestClient::builder()
+        .base_url("http://httpbin.org")
+        .proxy(&url)
+        .build()
+        .unwrap();
+
+    match client.get::<_, HttpBinAnything>(()) {
+        Err(Error::ConnectError(e)) => assert!(e.to_string().contains("407")),
+        _ => panic!("expected connect error"),
+    };
+}
+
+#[test]
+fn proxy_malformed_status() {
+    let responses = vec![b"HTTP/1.\r\n\r\n".to_vec(), b"HTTP/1.\xff200 OK\r\n\r\n".to_vec()];
+    let url = common::serve(responses);
+    let mut client = RestClient::builder()
+        .base_url("http://httpbin.org")
+        .proxy(&url)
+        .build()
+        .unwrap();
+
+    for _ in 0..2 {
+        match client.get::<_, HttpBinAnything>(()) {
+            Err(Error::ConnectError(e)) => assert!(e.to_string().contains("proxy refused tunnel")),
+            _ => panic!("expected connect error"),
+        };
+    }
+}
+
+#[test]
+fn invalid_proxy() {
+    match RestClient::builder().base_url("http://httpbin.org").proxy("socks5://proxy:1080").build() {
+        Err(Error::UrlError) => (),
+        _ => panic!("expected url error"),
+    };
+}
+
+#[test]
+fn proxy_from_env() {
+    // Other tests in this file set the proxy explicitly and are not affected
+    // by the environment.
+    let (proxy, requests) = common::proxy();
+    env::set_var("http_proxy", proxy.trim_start_matches("http://"));
+    env::set_var("NO_PROXY", "localhost,.example.com");
+    let mut client = RestClient::new("http://httpbin.org");
+    env::remove_var("http_proxy");
+    env::remove_var("NO_PROXY");
+
+    client.as_mut().unwrap().get::<_, HttpBinAnything>(()).unwrap();
+    assert!(requests.recv().unwrap().starts_with("CONNECT httpbin.org:80 HTTP/1.1\r\n"));
+
+    // Upper case `HTTP_PROXY` may come from the request headers of a CGI
+    // script and is not used.
+    env::set_var("HTTP_PROXY", proxy.trim_start_matches("http://"));
+    let mut client = RestClient::new("http://httpbin.org");
+    env::remove_var("HTTP_PROXY");
+
+    client.as_mut().unwrap().get::<_, HttpBinAnything>(()).unwrap();
+    assert!(requests.try_recv().is_err());
+}
@@ -0,0 +1,17 @@
+extern crate restson;
+
+use restson::{RestClient,RestPath,Error};
+
+struct HttpBinPurge {
+}
+
+impl RestPath<()> for HttpBinPurge {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("anything")) }
+}
+
+#[test]
+fn basic_purge() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.purge::<(), HttpBinPurge>(()).unwrap();
+}
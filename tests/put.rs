@@ -3,6 +3,9 @@ extern crate restson;
 #[macro_use]
 extern crate serde_derive;
 
+mod common;
+
+use std::time::{Duration,UNIX_EPOCH};
 use restson::{RestClient,RestPath,Error};
 
 #[derive(Serialize,Deserialize)]
@@ -59,3 +62,22 @@ fn put_capture_query_params() {
     assert_eq!(resp.json.data, "test data");
     assert_eq!(resp.url, "http://httpbin.org/put?a=2&b=abcd");
 }
+
+#[test]
+fn put_if_unmodified_since() {
+    let (url, requests) = common::serve_recording(vec![
+        common::response("200 OK", &[], b""),
+        common::response("412 Precondition Failed", &[], b""),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let time = UNIX_EPOCH + Duration::from_secs(784111777);
+    let data = HttpBinPut { data: String::from("test data")};
+    client.put_if_unmodified_since((), &data, time).unwrap();
+    assert!(requests.recv().unwrap().contains("If-Unmodified-Since: Sun, 06 Nov 1994 08:49:37 GMT\r\n"));
+
+    match client.put_if_unmodified_since((), &data, time) {
+        Err(Error::PreconditionFailed) => (),
+        _ => panic!("expected precondition failed error"),
+    };
+}
@@ -0,0 +1,115 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Serialize,Deserialize)]
+struct HttpBinGet {
+    url: String,
+}
+
+impl RestPath<u32> for HttpBinGet {
+    fn get_path(n: u32) -> Result<String,Error> { Ok(format!("redirect/{}", n)) }
+}
+
+impl RestPath<()> for HttpBinGet {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("redirect-to")) }
+}
+
+#[test]
+fn follow_redirects() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_follow_redirects(3);
+    let data: HttpBinGet = client.get(3).unwrap();
+    assert_eq!(data.url, "http://httpbin.org/get");
+}
+
+#[test]
+fn too_many_redirects() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_follow_redirects(2);
+    match client.get::<_, HttpBinGet>(3) {
+        Err(Error::TooManyRedirects) => (),
+        _ => panic!("expected too many redirects error"),
+    };
+}
+
+#[test]
+fn redirects_not_followed_by_default() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    match client.get::<_, HttpBinGet>(1) {
+        Err(Error::HttpError(302, _)) => (),
+        _ => panic!("expected 302 error"),
+    };
+}
+
+#[test]
+fn see_other_changes_post_to_get() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_follow_redirects(1);
+    let query = vec![("url", "/get"), ("status_code", "303")];
+    let data = HttpBinGet { url: String::new() };
+    let resp: HttpBinGet = client.post_capture_with((), &data, &query).unwrap();
+    assert_eq!(resp.url, "http://httpbin.org/get");
+}
+
+#[test]
+fn redirect_loop() {
+    let redirect = || common::response("302 Found", &[("Location", "/redirect-to")], b"");
+    let url = common::serve(vec![redirect(), redirect()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_follow_redirects(10);
+    match client.get::<_, HttpBinGet>(()) {
+        Err(Error::TooManyRedirects) => (),
+        _ => panic!("expected too many redirects error"),
+    };
+}
+
+#[test]
+fn redirect_to_other_origin_drops_credentials() {
+    let ok = common::response("200 OK", &[("Content-Type", "application/json")], br#"{"url": "done"}"#);
+    let (target, target_requests) = common::serve_recording(vec![ok]);
+    let location = format!("{}/redirect-to", target);
+    let (url, requests) = common::serve_recording(vec![common::response("302 Found", &[("Location", &location)], b"")]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_follow_redirects(1);
+    client.set_auth("user", "pass");
+    client.set_header_raw("Cookie", "session=secret");
+    client.set_header_raw("X-Test", "kept");
+    let data: HttpBinGet = client.get(()).unwrap();
+    assert_eq!(data.url, "done");
+
+    let head = requests.recv().unwrap().to_lowercase();
+    assert!(head.contains("authorization: basic"));
+    assert!(head.contains("cookie: session=secret"));
+
+    let head = target_requests.recv().unwrap().to_lowercase();
+    assert!(!head.contains("authorization"));
+    assert!(!head.contains("cookie"));
+    assert!(head.contains("x-test: kept"));
+}
+
+#[test]
+fn redirect_to_same_origin_keeps_credentials() {
+    let redirect = common::response("302 Found", &[("Location", "/redirect-to?next")], b"");
+    let ok = common::response("200 OK", &[("Content-Type", "application/json")], br#"{"url": "done"}"#);
+    let (url, requests) = common::serve_recording(vec![redirect, ok]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_follow_redirects(1);
+    client.set_auth("user", "pass");
+    let _: HttpBinGet = client.get(()).unwrap();
+
+    assert!(requests.recv().unwrap().to_lowercase().contains("authorization: basic"));
+    assert!(requests.recv().unwrap().to_lowercase().contains("authorization: basic"));
+}
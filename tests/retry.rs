@@ -0,0 +1,255 @@
+extern crate restson;
+extern crate hyper;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use std::cell::Cell;
+use std::net::TcpListener;
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration,Instant,SystemTime};
+use hyper::header::HttpDate;
+use restson::{RestClient,RestPath,Error,BackoffPolicy,CancelToken};
+
+#[derive(Serialize,Deserialize)]
+struct Resource {
+    id: u32,
+}
+
+impl RestPath<()> for Resource {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("resource")) }
+}
+
+fn unavailable() -> Vec<u8> {
+    common::response("503 Service Unavailable", &[], b"")
+}
+
+fn ok() -> Vec<u8> {
+    common::response("200 OK", &[], br#"{"id":1}"#)
+}
+
+#[test]
+fn retry_default_statuses() {
+    let url = common::serve(vec![unavailable(), common::response("429 Too Many Requests", &[], b""), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(2, Duration::from_millis(10));
+    let data: Resource = client.get(()).unwrap();
+    assert_eq!(data.id, 1);
+}
+
+#[test]
+fn retry_attempts_exhausted() {
+    let url = common::serve(vec![unavailable(), unavailable(), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(1, Duration::from_millis(10));
+    match client.get::<_, Resource>(()) {
+        Err(Error::HttpError(503, _)) => (),
+        _ => panic!("expected 503 error"),
+    };
+}
+
+#[test]
+fn retry_configured_statuses() {
+    let url = common::serve(vec![common::response("502 Bad Gateway", &[], b""), unavailable(), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(2, Duration::from_millis(10));
+    client.set_retryable_statuses(vec![502]);
+    match client.get::<_, Resource>(()) {
+        Err(Error::HttpError(503, _)) => (),
+        _ => panic!("expected 503 error"),
+    };
+}
+
+#[test]
+fn retry_not_idempotent() {
+    let url = common::serve(vec![unavailable(), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(2, Duration::from_millis(10));
+    match client.post((), &Resource { id: 1 }) {
+        Err(Error::HttpError(503, _)) => (),
+        _ => panic!("expected 503 error"),
+    };
+}
+
+#[test]
+fn retry_non_idempotent_enabled() {
+    let url = common::serve(vec![unavailable(), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(2, Duration::from_millis(10));
+    client.set_retry_non_idempotent(true);
+    client.post((), &Resource { id: 1 }).unwrap();
+}
+
+#[test]
+fn retry_request_error() {
+    // Connection is closed without response.
+    let url = common::serve(vec![Vec::new(), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(1, Duration::from_millis(10));
+    client.set_backoff_policy(BackoffPolicy::Constant);
+    let data: Resource = client.get(()).unwrap();
+    assert_eq!(data.id, 1);
+}
+
+#[test]
+fn retry_within_timeout() {
+    let url = common::serve(vec![unavailable(), unavailable(), unavailable(), unavailable(), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    // Retries after 100 and 300 ms, the next one would start after timeout.
+    client.set_retry(4, Duration::from_millis(100));
+    client.set_timeout(Duration::from_millis(500));
+    let start = Instant::now();
+    match client.get::<_, Resource>(()) {
+        Err(Error::HttpError(503, _)) => (),
+        _ => panic!("expected 503 error"),
+    };
+    assert!(start.elapsed() < Duration::from_millis(500));
+}
+
+#[test]
+fn retry_idempotency_key() {
+    let (url, requests) = common::serve_recording(vec![unavailable(), ok(), unavailable()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(2, Duration::from_millis(10));
+    client.post_idempotent((), &Resource { id: 1 }, "order-1234").unwrap();
+    assert!(requests.recv().unwrap().contains("Idempotency-Key: order-1234\r\n"));
+    assert!(requests.recv().unwrap().contains("Idempotency-Key: order-1234\r\n"));
+
+    // Neither the key nor the retries apply to later requests.
+    match client.post((), &Resource { id: 1 }) {
+        Err(Error::HttpError(503, _)) => (),
+        _ => panic!("expected 503 error"),
+    };
+    assert!(!requests.recv().unwrap().contains("Idempotency-Key"));
+}
+
+#[test]
+fn retry_after_seconds() {
+    let url = common::serve(vec![common::response("429 Too Many Requests", &[("Retry-After", "1")], b""), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(1, Duration::from_millis(10));
+    let start = Instant::now();
+    client.get::<_, Resource>(()).unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[test]
+fn retry_after_date() {
+    let date = HttpDate::from(SystemTime::now() + Duration::from_secs(2)).to_string();
+    let url = common::serve(vec![
+        unavailable(),
+        common::response("503 Service Unavailable", &[("Retry-After", &date)], b""),
+        common::response("503 Service Unavailable", &[("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT")], b""),
+        ok(),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    // First retry uses the backoff, the second waits until the date and the
+    // third is immediate since the date has passed.
+    client.set_retry(3, Duration::from_millis(10));
+    client.set_backoff_policy(BackoffPolicy::Constant);
+    let start = Instant::now();
+    client.get::<_, Resource>(()).unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert!(start.elapsed() < Duration::from_secs(3));
+}
+
+#[test]
+fn retry_after_exceeds_timeout() {
+    let url = common::serve(vec![common::response("503 Service Unavailable", &[("Retry-After", "10")], b""), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(1, Duration::from_millis(10));
+    client.set_timeout(Duration::from_secs(1));
+    match client.get::<_, Resource>(()) {
+        Err(Error::HttpError(503, _)) => (),
+        _ => panic!("expected 503 error"),
+    };
+}
+
+// Client that counts the attempts it makes.
+#[test]
+fn retry_wait_cancelled() {
+    let url = common::serve(vec![common::response("503 Service Unavailable", &[("Retry-After", "5")], b""), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_retry(1, Duration::from_millis(10));
+
+    let token = CancelToken::new();
+    let cancel = token.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(300));
+        cancel.cancel();
+    });
+
+    let start = Instant::now();
+    match client.get_cancellable::<_, Resource>((), &token) {
+        Err(Error::Cancelled) => (),
+        _ => panic!("expected cancelled error"),
+    };
+    assert!(start.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn retry_after_exceeds_max_delay() {
+    let url = common::serve(vec![common::response("429 Too Many Requests", &[("Retry-After", "3600")], b""), ok()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    client.set_retry(1, Duration::from_millis(10));
+    client.set_max_retry_delay(Duration::from_secs(5));
+    let start = Instant::now();
+    match client.get::<_, Resource>(()) {
+        Err(Error::HttpError(429, _)) => (),
+        _ => panic!("expected 429 error"),
+    };
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+fn counted_client(url: &str) -> (RestClient, Rc<Cell<u32>>) {
+    let mut client = RestClient::new(url).unwrap();
+    let attempts = Rc::new(Cell::new(0));
+    let count = attempts.clone();
+    client.set_observer(move |_| count.set(count.get() + 1));
+    (client, attempts)
+}
+
+#[test]
+fn retry_not_idempotent_not_sent() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+    let (mut client, attempts) = counted_client(&url);
+
+    // Connection is refused, so the request never reaches the server.
+    client.set_retry(2, Duration::from_millis(10));
+    match client.post((), &Resource { id: 1 }) {
+        Err(Error::ConnectError(_)) => (),
+        _ => panic!("expected connect error"),
+    };
+    assert_eq!(attempts.get(), 3);
+}
+
+#[test]
+fn retry_not_idempotent_sent() {
+    // Connection is closed without response after the request is read.
+    let url = common::serve(vec![Vec::new(), ok()]);
+    let (mut client, attempts) = counted_client(&url);
+
+    client.set_retry(2, Duration::from_millis(10));
+    match client.post((), &Resource { id: 1 }) {
+        Err(ref e) if e.request_sent() == Some(true) => (),
+        _ => panic!("expected error after request was sent"),
+    };
+    assert_eq!(attempts.get(), 1);
+}
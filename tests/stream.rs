@@ -0,0 +1,143 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use std::cell::Cell;
+use std::rc::Rc;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinStream {
+    id: u32,
+}
+
+impl RestPath<u32> for HttpBinStream {
+    fn get_path(n: u32) -> Result<String,Error> { Ok(format!("stream/{}", n)) }
+}
+
+#[test]
+fn get_stream() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let ids: Vec<u32> = client.get_stream::<_, HttpBinStream>(20).unwrap()
+        .map(|item| item.unwrap().id)
+        .collect();
+
+    assert_eq!(ids, (0..20).collect::<Vec<u32>>());
+}
+
+#[test]
+fn get_stream_split_lines() {
+    let chunks: &[&str] = &["{\"i", "d\":0}\n{\"id\":1}\n\n{\"id\"", ":2}"];
+    let mut res = String::from("HTTP/1.1 200 OK\r\nConnection: close\r\nTransfer-Encoding: chunked\r\n\r\n");
+    for chunk in chunks {
+        res.push_str(&format!("{:x}\r\n{}\r\n", chunk.len(), chunk));
+    }
+    res.push_str("0\r\n\r\n");
+
+    let url = common::serve(vec![res.into_bytes()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let ids: Vec<u32> = client.get_stream::<_, HttpBinStream>(3).unwrap()
+        .map(|item| item.unwrap().id)
+        .collect();
+
+    assert_eq!(ids, vec![0, 1, 2]);
+}
+
+#[test]
+fn get_stream_invalid_line() {
+    let body = b"{\"id\":0}\r\nnot json\r\n{\"id\":2}";
+    let url = common::serve(vec![common::response("200 OK", &[("Content-Type", "application/x-ndjson")], body)]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    let mut stream = client.get_stream::<_, HttpBinStream>(3).unwrap();
+    assert_eq!(stream.next().unwrap().unwrap().id, 0);
+    match stream.next() {
+        Some(Err(ref e @ Error::ParseError(_))) => assert_eq!(e.response_body(), Some("not json")),
+        _ => panic!("expected parse error"),
+    };
+    assert_eq!(stream.next().unwrap().unwrap().id, 2);
+    assert!(stream.next().is_none());
+}
+
+#[test]
+fn get_stream_stop() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    {
+        let mut stream = client.get_stream::<_, HttpBinStream>(100).unwrap();
+        assert_eq!(stream.next().unwrap().unwrap().id, 0);
+        stream.stop();
+        assert!(stream.next().is_none());
+    }
+
+    // The stopped connection is not reused.
+    let mut stream = client.get_stream::<_, HttpBinStream>(1).unwrap();
+    assert_eq!(stream.next().unwrap().unwrap().id, 0);
+    assert!(stream.next().is_none());
+    drop(stream);
+
+    assert_eq!(client.connections_established(), 2);
+}
+
+#[test]
+fn get_stream_error_status() {
+    let url = common::serve(vec![
+        common::response("404 Not Found", &[], b"not found"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    match client.get_stream::<_, HttpBinStream>(1) {
+        Err(Error::HttpError(404, ref body)) if body == "not found" => (),
+        _ => panic!("expected http error"),
+    };
+}
+
+#[test]
+fn get_stream_response_handling() {
+    let body = b"{\"id\":0}\n";
+    let (url, requests) = common::serve_recording(vec![
+        common::response("200 OK", &[("Content-Type", "application/x-ndjson")], body),
+        common::response("500 Internal Server Error", &[], b"error page"),
+    ]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_accept_compression(true);
+    client.set_skip_error_body(true);
+
+    let received = Rc::new(Cell::new(0));
+    let count = received.clone();
+    client.set_accounting_callback(move |_, _, bytes| count.set(count.get() + bytes));
+
+    assert_eq!(client.get_stream::<_, HttpBinStream>(1).unwrap().count(), 1);
+    assert!(!requests.recv().unwrap().to_lowercase().contains("accept-encoding"));
+    assert_eq!(received.get(), body.len() as u64);
+    assert!(client.last_timing().is_some());
+
+    match client.get_stream::<_, HttpBinStream>(1) {
+        Err(Error::HttpError(500, ref body)) if body.is_empty() => (),
+        _ => panic!("expected http error without body"),
+    };
+}
+
+struct HttpBinBytes {
+}
+
+impl RestPath<u32> for HttpBinBytes {
+    fn get_path(n: u32) -> Result<String,Error> { Ok(format!("stream-bytes/{}", n)) }
+}
+
+#[test]
+fn get_fold() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let (len, sum) = client.get_fold::<_, HttpBinBytes, _, _>(100000, (0, 0u64), |(len, sum), chunk| {
+        (len + chunk.len(), sum + chunk.iter().map(|&b| u64::from(b)).sum::<u64>())
+    }).unwrap();
+
+    assert_eq!(len, 100000);
+    assert_eq!(sum, (0..100000u64).map(|i| (i % 26) + 97).sum::<u64>());
+}
@@ -0,0 +1,125 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::net::TcpListener;
+use std::thread;
+use std::time::{Duration,Instant};
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinDelay {
+}
+
+impl RestPath<u32> for HttpBinDelay {
+    fn get_path(delay: u32) -> Result<String,Error> { Ok(format!("delay/{}", delay)) }
+}
+
+#[derive(Deserialize)]
+struct HttpBinDrip {
+}
+
+impl RestPath<()> for HttpBinDrip {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("drip")) }
+}
+
+#[test]
+fn read_timeout() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_read_timeout(Some(Duration::from_millis(100)));
+
+    match client.get::<_, HttpBinDelay>(1) {
+        Err(Error::TimeoutError) => (),
+        _ => panic!("expected timeout error"),
+    };
+}
+
+#[test]
+fn read_timeout_not_exceeded() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_read_timeout(Some(Duration::from_secs(5)));
+    client.set_write_timeout(Some(Duration::from_secs(5)));
+
+    client.get::<_, HttpBinDelay>(0).unwrap();
+}
+
+#[test]
+fn timeout() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_timeout(Duration::from_millis(100));
+
+    match client.get::<_, HttpBinDelay>(1) {
+        Err(Error::TimeoutError) => (),
+        _ => panic!("expected timeout error"),
+    };
+}
+
+#[test]
+fn timeout_covers_body() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    // Data keeps arriving so read timeout would not expire.
+    client.set_timeout(Duration::from_millis(500));
+    client.set_read_timeout(Some(Duration::from_secs(5)));
+
+    let query = vec![("numbytes", "5"), ("duration", "2")];
+    match client.get_with::<_, HttpBinDrip>((), &query) {
+        Err(Error::TimeoutError) => (),
+        _ => panic!("expected timeout error"),
+    };
+}
+
+#[test]
+fn request_timeout_override() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_timeout(Duration::from_millis(100));
+    client.set_request_timeout(Duration::from_secs(0));
+    client.get::<_, HttpBinDelay>(1).unwrap();
+
+    client.set_timeout(Duration::from_secs(0));
+    client.set_request_timeout(Duration::from_millis(100));
+    match client.get::<_, HttpBinDelay>(1) {
+        Err(Error::TimeoutError) => (),
+        _ => panic!("expected timeout error"),
+    };
+
+    client.get::<_, HttpBinDelay>(1).unwrap();
+}
+
+#[test]
+fn connect_timeout() {
+    // Server accepts TCP connections but never completes the TLS handshake.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("https://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        let _streams: Vec<_> = listener.incoming().collect();
+    });
+
+    let mut client = RestClient::builder()
+        .base_url(&url)
+        .connect_timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    match client.get::<_, HttpBinDelay>(1) {
+        Err(Error::TimeoutError) => (),
+        _ => panic!("expected timeout error"),
+    };
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn connect_timeout_not_applied_to_response() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.set_connect_timeout(Some(Duration::from_millis(200)));
+    client.set_read_timeout(Some(Duration::from_secs(5)));
+
+    client.get::<_, HttpBinDelay>(1).unwrap();
+}
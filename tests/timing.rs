@@ -0,0 +1,70 @@
+extern crate restson;
+extern crate hyper;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::time::Duration;
+use hyper::header::ContentLength;
+use restson::{RestClient,RestPath,Error};
+
+#[derive(Deserialize)]
+struct HttpBinDelay {
+}
+
+impl RestPath<u32> for HttpBinDelay {
+    fn get_path(delay: u32) -> Result<String,Error> { Ok(format!("delay/{}", delay)) }
+}
+
+#[test]
+fn timing_new_connection() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    assert!(client.last_timing().is_none());
+    client.get::<_, HttpBinDelay>(1).unwrap();
+
+    let timing = client.last_timing().unwrap();
+    assert!(timing.dns.is_some());
+    assert!(timing.connect.is_some());
+    assert!(timing.tls.is_none());
+    assert!(timing.first_byte >= Duration::from_secs(1));
+    assert!(timing.total >= timing.first_byte);
+}
+
+#[test]
+fn timing_reused_connection() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    client.get::<_, HttpBinDelay>(0).unwrap();
+    client.get::<_, HttpBinDelay>(0).unwrap();
+
+    let timing = client.last_timing().unwrap();
+    assert!(timing.dns.is_none());
+    assert!(timing.connect.is_none());
+    assert!(timing.tls.is_none());
+}
+
+#[test]
+fn timing_tls() {
+    let mut client = RestClient::new("https://httpbin.org").unwrap();
+
+    client.get::<_, HttpBinDelay>(0).unwrap();
+
+    let timing = client.last_timing().unwrap();
+    assert!(timing.tls.is_some());
+    assert!(timing.first_byte >= timing.dns.unwrap() + timing.connect.unwrap() + timing.tls.unwrap());
+}
+
+#[test]
+fn response_info_metrics() {
+    let mut client = RestClient::new("http://httpbin.org").unwrap();
+
+    let (_, info) = client.get_with_response::<_, HttpBinDelay>(1).unwrap();
+    assert!(info.elapsed >= Duration::from_secs(1));
+
+    let length = info.headers.get::<ContentLength>().unwrap();
+    assert_eq!(info.body_size, **length);
+
+    let info = client.head::<_, HttpBinDelay>(0).unwrap();
+    assert_eq!(info.body_size, 0);
+}
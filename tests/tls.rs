@@ -0,0 +1,96 @@
+extern crate restson;
+extern crate native_tls;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::io::{BufRead,BufReader,Write};
+use std::net::TcpListener;
+use std::thread;
+use native_tls::{Pkcs12,TlsAcceptor};
+use restson::{RestClient,RestPath,Error};
+
+// Certificates in tests/certs: `ca.der` is a self-signed test CA and
+// `server.p12` (password "restson") holds a certificate for localhost and
+// 127.0.0.1 issued by it.
+const CA: &[u8] = include_bytes!("certs/ca.der");
+
+#[derive(Deserialize)]
+struct Status {
+    status: String,
+}
+
+impl RestPath<()> for Status {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("status")) }
+}
+
+// Serves the same JSON response over HTTPS to every connection that
+// completes the handshake. Returns the base URL of the server.
+fn serve_tls() -> String {
+    let identity = Pkcs12::from_der(include_bytes!("certs/server.p12"), "restson").unwrap();
+    let acceptor = TlsAcceptor::builder(identity).unwrap().build().unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("https://localhost:{}", listener.local_addr().unwrap().port());
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = match acceptor.accept(stream.unwrap()) {
+                Ok(stream) => stream,
+                Err(_) => continue,
+            };
+
+            let mut line = String::new();
+            {
+                let mut reader = BufReader::new(&mut stream);
+                while reader.read_line(&mut line).unwrap() > 0 && !line.ends_with("\r\n\r\n") {}
+            }
+
+            let body = r#"{"status":"ok"}"#;
+            let _ = write!(stream, "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+        }
+    });
+
+    url
+}
+
+#[test]
+fn untrusted_certificate() {
+    let mut client = RestClient::new(&serve_tls()).unwrap();
+
+    match client.get::<_, Status>(()) {
+        Err(Error::TlsError(_)) => (),
+        _ => panic!("expected TLS error"),
+    };
+}
+
+#[test]
+fn custom_root_certificate() {
+    let mut client = RestClient::builder()
+        .base_url(&serve_tls())
+        .add_root_certificate(CA)
+        .build()
+        .unwrap();
+
+    let data: Status = client.get(()).unwrap();
+    assert_eq!(data.status, "ok");
+}
+
+#[test]
+fn accept_invalid_certs() {
+    let mut client = RestClient::builder()
+        .base_url(&serve_tls())
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap();
+
+    let data: Status = client.get(()).unwrap();
+    assert_eq!(data.status, "ok");
+}
+
+#[test]
+fn invalid_root_certificate() {
+    match RestClient::builder().base_url("https://localhost").add_root_certificate(b"invalid").build() {
+        Err(Error::HttpClientError) => (),
+        _ => panic!("expected client error"),
+    };
+}
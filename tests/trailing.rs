@@ -0,0 +1,50 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use restson::{RestClient,RestPath,Error,TrailingDataBehavior};
+
+mod common;
+
+#[derive(Serialize,Deserialize)]
+struct Record {
+    id: u32,
+}
+
+impl RestPath<()> for Record {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("records")) }
+}
+
+fn concatenated() -> Vec<u8> {
+    common::response("200 OK", &[("Content-Type", "application/json")], b"{\"id\":1}\n{\"id\":2}\n")
+}
+
+#[test]
+fn trailing_data_error() {
+    let url = common::serve(vec![concatenated()]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    match client.get::<_, Record>(()) {
+        Err(Error::ParseError(_)) => (),
+        _ => panic!("expected parse error"),
+    };
+}
+
+#[test]
+fn trailing_data_ignore() {
+    let url = common::serve(vec![concatenated(), concatenated(), common::response("200 OK", &[], b"")]);
+    let mut client = RestClient::new(&url).unwrap();
+    client.set_trailing_data_behavior(TrailingDataBehavior::Ignore);
+
+    let data: Record = client.get(()).unwrap();
+    assert_eq!(data.id, 1);
+
+    let data: Record = client.post_capture((), &Record { id: 3 }).unwrap();
+    assert_eq!(data.id, 1);
+
+    match client.get::<_, Record>(()) {
+        Err(Error::ParseError(_)) => (),
+        _ => panic!("expected parse error"),
+    };
+}
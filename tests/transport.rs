@@ -0,0 +1,69 @@
+extern crate restson;
+
+#[macro_use]
+extern crate serde_derive;
+
+use std::net::TcpListener;
+use restson::{RestClient,RestPath,Error};
+
+mod common;
+
+#[derive(Deserialize)]
+struct Status {
+}
+
+impl RestPath<()> for Status {
+    fn get_path(_: ()) -> Result<String,Error> { Ok(String::from("status")) }
+}
+
+// URL of a local port that nothing listens on.
+fn closed_port() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    format!("http://{}", listener.local_addr().unwrap())
+}
+
+#[test]
+fn connect_error() {
+    let mut client = RestClient::new(&closed_port()).unwrap();
+
+    match client.get::<_, Status>(()) {
+        Err(Error::ConnectError(e)) => assert!(e.to_string().contains("refused")),
+        _ => panic!("expected connect error"),
+    };
+}
+
+#[test]
+fn tls_error() {
+    // Plain HTTP server does not answer the TLS handshake.
+    let url = common::serve(vec![common::response("200 OK", &[], b"{}")]);
+    let mut client = RestClient::new(&url.replace("http:", "https:")).unwrap();
+
+    match client.get::<_, Status>(()) {
+        Err(Error::TlsError(_)) => (),
+        _ => panic!("expected TLS error"),
+    };
+}
+
+#[test]
+fn io_error() {
+    // Connection is closed before the whole body is sent.
+    let mut truncated = common::response("200 OK", &[("Content-Type", "application/json")], b"{}");
+    truncated.truncate(truncated.len() - 1);
+    let url = common::serve(vec![truncated]);
+    let mut client = RestClient::new(&url).unwrap();
+
+    match client.get::<_, Status>(()) {
+        Err(Error::IoError(_)) => (),
+        _ => panic!("expected I/O error"),
+    };
+}
+
+#[test]
+fn request_sent() {
+    let mut client = RestClient::new(&closed_port()).unwrap();
+    assert_eq!(client.get::<_, Status>(()).err().unwrap().request_sent(), Some(false));
+
+    let url = common::serve(vec![Vec::new()]);
+    let mut client = RestClient::new(&url).unwrap();
+    assert_eq!(client.get::<_, Status>(()).err().unwrap().request_sent(), Some(true));
+}